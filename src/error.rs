@@ -0,0 +1,115 @@
+use core::error::Error;
+use core::fmt;
+use alloc::string::String;
+use crate::graph::TimeInconsistencyError;
+
+pub type TimeResult<T> = Result<T,TimeError>;
+
+/// Errors which could occur when computing on time data
+///
+/// The errors of the other parts of this crate (time constraints, parsing)
+/// could be converted to it, so they compose in one `Result` type.
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub enum TimeError {
+    /// A finite time point goes beyond the future infinite
+    FutureOverflow,
+
+    /// A finite time point goes beyond the past infinite
+    PastOverflow,
+
+    /// The computation leads to an unexpected empty interval
+    EmptyInterval,
+
+    /// The propagation of time constraints failed
+    InconsistentConstraints(TimeInconsistencyError),
+
+    /// The parsing of time data failed
+    ParseError(ParseError),
+}
+
+impl Error for TimeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            TimeError::InconsistentConstraints(err) => Some(err),
+            TimeError::ParseError(err) => Some(err),
+            _ => None
+        }
+    }
+}
+
+impl From<TimeInconsistencyError> for TimeError {
+    #[inline]
+    fn from(err: TimeInconsistencyError) -> Self { TimeError::InconsistentConstraints(err) }
+}
+
+impl From<ParseError> for TimeError {
+    #[inline]
+    fn from(err: ParseError) -> Self { TimeError::ParseError(err) }
+}
+
+impl fmt::Display for TimeError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            TimeError::FutureOverflow => formatter.write_str("time overflow (towards future infinite)"),
+            TimeError::PastOverflow => formatter.write_str("time overflow (towards past infinite)"),
+            TimeError::EmptyInterval => formatter.write_str("unexpected empty time interval"),
+            TimeError::InconsistentConstraints(err) => fmt::Display::fmt(err, formatter),
+            TimeError::ParseError(err) => fmt::Display::fmt(err, formatter),
+        }
+    }
+}
+
+
+/// Error which occurs when parsing time data
+#[derive(Clone,Debug,PartialEq,Eq)]
+pub struct ParseError {
+    message: String
+}
+
+impl ParseError {
+    #[inline]
+    pub(crate) fn new<S:Into<String>>(message: S) -> Self {
+        Self { message: message.into() }
+    }
+
+    /// The description of the parsing error
+    #[inline]
+    pub fn message(&self) -> &str { &self.message }
+}
+
+impl Error for ParseError { }
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        write!(formatter, "parse error: {}", self.message)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use core::error::Error;
+    use crate::*;
+    use crate::graph::*;
+
+    fn schedule(s: &str) -> TimeResult<TimePropagation>
+    {
+        let delay: TimeSpan = s.parse()?;
+        let mut graph = TimeGraph::with_size(2);
+        graph.propagate(((0,1), delay))?;
+        Ok(graph.propagate(((0,1), TimeSpan::after(TimeValue::default())))?)
+    }
+
+    #[test]
+    fn conversions()
+    {
+        assert_eq!( schedule("[1h,2h]"), Ok(TimePropagation::Unchanged));
+        assert_eq!( schedule("[-2h,-1h]"), Err(TimeError::InconsistentConstraints(TimeInconsistencyError::Recovered)));
+        assert!( matches!(schedule("[1h,"), Err(TimeError::ParseError(_))));
+
+        let err = schedule("[-2h,-1h]").unwrap_err();
+        assert_eq!( err.to_string(), TimeInconsistencyError::Recovered.to_string());
+        assert!( err.source().is_some());
+        assert!( TimeError::EmptyInterval.source().is_none());
+    }
+}
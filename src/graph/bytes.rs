@@ -0,0 +1,183 @@
+use crate::*;
+use crate::graph::{Instant, TimeGraph};
+
+// the two encodings of the matrix (the smallest one is chosen)
+const DENSE: u8 = b'D';
+const SPARSE: u8 = b'S';
+
+// the largest size of a graph in the sparse encoding: unlike the dense one,
+// its length does not bound the memory needed by the decoded graph
+const MAX_SPARSE_SIZE: Instant = 1 << 12;
+
+impl TimeGraph {
+
+    /// Encodes the time graph in a compact binary format
+    ///
+    /// The encoding starts with a tag byte and the size of the graph (as a
+    /// little-endian `u32`), followed by the matrix of the lower bounds:
+    /// * either all its cells (as little-endian `i64` ticks), in the storage order,
+    /// * or only the constrained ones (an `u32` count followed by, for each cell,
+    ///   its `u32` position in the storage order and its `i64` ticks).
+    ///
+    /// The second form is chosen if it is smaller (typically, for sparse graphs)
+    /// and if the graph has at most 4096 instants.
+    /// The encoding is exact: the decoded graph is the same, including its
+    /// propagated state (see [`Self::from_bytes`]).
+    pub fn to_bytes(&self) -> Vec<u8>
+    {
+        // the storage could be larger than the matrix (see `shrink_to_fit`)
+        let data = &self.data[..(self.size * self.size) as usize];
+        let cells = data.len();
+        let constrained = data.iter()
+            .enumerate()
+            .filter(|&(ij, t)| *t != unconstrained(ij as Instant))
+            .collect::<Vec<_>>();
+
+        let mut bytes = vec![];
+        if self.size <= MAX_SPARSE_SIZE && 4 + 12 * constrained.len() < 8 * cells {
+            bytes.reserve_exact(9 + 12 * constrained.len());
+            bytes.push(SPARSE);
+            bytes.extend_from_slice(&self.size.to_le_bytes());
+            bytes.extend_from_slice(&(constrained.len() as u32).to_le_bytes());
+            constrained.into_iter().for_each(|(ij, t)| {
+                bytes.extend_from_slice(&(ij as u32).to_le_bytes());
+                bytes.extend_from_slice(&t.as_ticks().to_le_bytes());
+            });
+        } else {
+            bytes.reserve_exact(5 + 8 * cells);
+            bytes.push(DENSE);
+            bytes.extend_from_slice(&self.size.to_le_bytes());
+            data.iter().for_each(|t| bytes.extend_from_slice(&t.as_ticks().to_le_bytes()));
+        }
+        bytes
+    }
+
+    /// Decodes a time graph encoded by [`Self::to_bytes`]
+    ///
+    /// The constraints are not propagated again, since the encoded graph was.
+    /// An error is returned if the bytes are truncated or malformed (in particular,
+    /// if the size of the graph does not match the length of the dense matrix or
+    /// exceeds 4096 instants in the sparse form).
+    pub fn from_bytes(bytes: &[u8]) -> Result<TimeGraph, TimeError>
+    {
+        let mut reader = ByteReader(bytes);
+        let tag = reader.take::<1>()?[0];
+        let size = Instant::from_le_bytes(reader.take()?);
+        let cells = (size as u64).pow(2);
+        if cells > Instant::MAX as u64 {
+            return Err(malformed("too many instants"));
+        }
+        let mut graph = TimeGraph::default();
+        match tag {
+            DENSE => {
+                // checked before any allocation
+                if reader.0.len() as u64 != 8 * cells {
+                    return Err(malformed("unexpected length of the dense matrix"));
+                }
+                graph.size = size;
+                graph.data = reader.0.chunks_exact(8)
+                    .map(|t| TimeValue::from_ticks(i64::from_le_bytes(t.try_into().unwrap())))
+                    .collect();
+            }
+            SPARSE => {
+                if size > MAX_SPARSE_SIZE {
+                    return Err(malformed("too many instants for the sparse matrix"));
+                }
+                let count = u32::from_le_bytes(reader.take()?);
+                if reader.0.len() as u64 != 12 * count as u64 {
+                    return Err(malformed("unexpected length of the sparse matrix"));
+                }
+                graph.resize(size);
+                for _ in 0..count {
+                    let ij = u32::from_le_bytes(reader.take()?);
+                    let t = i64::from_le_bytes(reader.take()?);
+                    *graph.data.get_mut(ij as usize)
+                        .ok_or_else(|| malformed("cell out of the matrix"))? = TimeValue::from_ticks(t);
+                }
+            }
+            _ => return Err(malformed("unknown encoding"))
+        }
+        Ok(graph)
+    }
+}
+
+// the value of a cell (in the storage order) of an unconstrained graph
+#[inline]
+fn unconstrained(ij: Instant) -> TimeValue
+{
+    // the diagonal cells are at positions i*i+i
+    let i = ij.isqrt();
+    if ij == i*i + i { TimeValue::default() } else { -TimeValue::INFINITE }
+}
+
+#[inline]
+fn malformed(reason: &str) -> TimeError
+{
+    ParseError::new(format!("malformed time graph encoding: {}", reason)).into()
+}
+
+struct ByteReader<'a>(&'a [u8]);
+
+impl ByteReader<'_> {
+    fn take<const N:usize>(&mut self) -> Result<[u8;N], TimeError>
+    {
+        let (head, tail) = self.0.split_first_chunk::<N>()
+            .ok_or_else(|| malformed("truncated data"))?;
+        self.0 = tail;
+        Ok(*head)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::graph::*;
+    use super::MAX_SPARSE_SIZE;
+
+    #[test]
+    fn bytes()
+    {
+        let t = TimeValue::from_secs;
+        let mut graph = TimeGraph::with_size(30);
+        graph.propagate(((0,1), TimeSpan::new(t(1), t(5)))).unwrap();
+        graph.propagate(((2,1), TimeSpan::after(t(3)))).unwrap();
+
+        // sparse graph
+        let bytes = graph.to_bytes();
+        assert_eq!( bytes[0], b'S' );
+        assert!( bytes.len() < 8 * 30 * 30 );
+        assert_eq!( TimeGraph::from_bytes(&bytes), Ok(graph.clone()) );
+        assert_eq!( TimeGraph::from_bytes(&bytes).unwrap().timespan(1,2), graph.timespan(1,2) );
+
+        // dense graph
+        let mut dense = TimeGraph::with_size(4);
+        dense.extend((0..3).map(|i| ((i, i+1), TimeSpan::new(t(1), t(2))))).unwrap();
+        let bytes = dense.to_bytes();
+        assert_eq!( bytes[0], b'D' );
+        assert_eq!( bytes.len(), 5 + 8 * 16 );
+        let decoded = TimeGraph::from_bytes(&bytes).unwrap();
+        assert_eq!( decoded, dense );
+        assert_eq!( decoded.size(), 4 );
+        assert_eq!( decoded.timespan(0,3), TimeSpan::new(t(3), t(6)) );
+
+        assert_eq!( TimeGraph::from_bytes(&TimeGraph::default().to_bytes()), Ok(TimeGraph::default()) );
+        graph.shrink_to_fit();
+        assert_eq!( TimeGraph::from_bytes(&graph.to_bytes()).unwrap().size(), 3 );
+        assert_eq!( TimeGraph::from_bytes(&TimeGraph::with_size(5).to_bytes()).unwrap().size(), 5 );
+
+        // malformed data
+        assert!( TimeGraph::from_bytes(&[]).is_err() );
+        assert!( TimeGraph::from_bytes(&bytes[..bytes.len()-1]).is_err() );
+        assert!( TimeGraph::from_bytes(b"X\0\0\0\0").is_err() );
+        assert!( TimeGraph::from_bytes(b"S\x01\0\0\0\x01\0\0\0\x07\0\0\0\0\0\0\0\0\0\0\0").is_err() );
+
+        // the size is checked before allocating the matrix
+        assert!( TimeGraph::from_bytes(b"S\xff\xff\0\0\0\0\0\0").is_err() );
+        assert!( TimeGraph::from_bytes(b"D\xff\xff\0\0\0\0\0\0\0\0\0\0").is_err() );
+        let mut header = vec![b'S'];
+        header.extend_from_slice(&(MAX_SPARSE_SIZE + 1).to_le_bytes());
+        header.extend_from_slice(&0_u32.to_le_bytes());
+        assert!( TimeGraph::from_bytes(&header).is_err() );
+    }
+}
@@ -1,6 +1,6 @@
-use std::fmt;
-use std::iter::FusedIterator;
-use std::mem::swap;
+use core::fmt;
+use core::iter::FusedIterator;
+use core::mem::swap;
 use crate::*;
 use crate::graph::{Instant, TimeConstraint, TimeGraph};
 
@@ -38,6 +38,15 @@ impl TimeGraph {
         })
     }
 
+    /// Gets an iterator over the instants which are constrained with `i`
+    ///
+    /// These are the instants `j != i` such as the constraint between `i` and `j`
+    /// is not `]-oo,+oo[` (i.e. the adjacency of `i` in the network of constraints).
+    #[inline]
+    pub fn neighbors(&self, i: Instant) -> impl Iterator<Item=Instant> + '_ {
+        self.constraints_from(i).map(|k| k.to())
+    }
+
     /// Gets an iterator over all the propagated constraints of the graph.
     ///
     /// Only relevant constraints are iterated.
@@ -205,37 +214,28 @@ impl<'a> Iterator for TimeConstraintFromIter<'a> {
         if self.i >= self.graph.size() {
             // this bound checking guarantees also that
             // this iterator is a FusedIterator
-            None
-        } else {
-            let current = TimeGraphConstraint {
-                from: self.i,
-                to: self.j,
-                graph: self.graph
-            };
-            loop { // search the next current
-                self.j += 1;
-
-                if self.j == self.graph.size() {
-                    self.i = self.graph.size();
-                    break;
-
-                } else if self.i != self.j &&
-                    (unsafe { !self.graph.lower(self.i,self.j).is_past_infinite() }
-                        || unsafe { !self.graph.lower(self.j,self.i).is_past_infinite() }) {
-                    break;
-                }
+            return None;
+        }
+        while self.j < self.graph.size() {
+            let j = self.j;
+            self.j += 1;
+            if self.i != j &&
+                (unsafe { !self.graph.lower(self.i,j).is_past_infinite() }
+                    || unsafe { !self.graph.lower(j,self.i).is_past_infinite() }) {
+                return Some(TimeGraphConstraint { from: self.i, to: j, graph: self.graph });
             }
-            Some(current)
         }
+        None
     }
 
     #[inline]
     fn size_hint(&self) -> (usize, Option<usize>)
     {
-        let all_constraints = (self.graph.size() * self.graph.size() - self.graph.size())/2;
-        let previous_constraints =  (self.i * self.i - self.i)/2;
-        let max = all_constraints - previous_constraints + 1 - self.j;
-        (0, Some(max as usize))
+        if self.i >= self.graph.size() {
+            (0, Some(0))
+        } else {
+            (0, Some(self.graph.size().saturating_sub(self.j) as usize))
+        }
     }
 }
 
@@ -260,4 +260,41 @@ impl<K:TimeConstraint> Extend<K> for TimeGraph
     fn extend<T: IntoIterator<Item=K>>(&mut self, iter: T) {
         self.extend(iter).expect("inconsistent set of time constraints");
     }
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::graph::*;
+
+    #[test]
+    fn constraints_from()
+    {
+        let mut graph = TimeGraph::with_size(3);
+        graph.propagate(((1,2), TimeSpan::after(TimeValue::default()))).unwrap();
+
+        let to = |i| graph.constraints_from(i).map(|k| k.to()).collect::<Vec<_>>();
+        assert_eq!( to(1), vec![2] );
+        assert_eq!( to(2), vec![1] );
+        assert_eq!( to(0), Vec::<Instant>::new() );
+        assert_eq!( graph.constraints_from(2).size_hint(), (0, Some(3)) );
+        assert_eq!( graph.constraints_from(5).size_hint(), (0, Some(0)) );
+        assert_eq!( TimeGraph::default().constraints_from(0).count(), 0 );
+    }
+
+    #[test]
+    fn neighbors()
+    {
+        let mut graph = TimeGraph::with_size(5);
+        graph.propagate(((0,1), TimeSpan::after(TimeValue::default()))).unwrap();
+        graph.propagate(((3,1), TimeSpan::before(TimeValue::from_secs(2)))).unwrap();
+
+        assert_eq!( graph.neighbors(1).collect::<Vec<_>>(), vec![0, 3] );
+        // the propagation constrains also t3 - t0 in [-2s,+oo[
+        assert_eq!( graph.neighbors(0).collect::<Vec<_>>(), vec![1, 3] );
+        assert_eq!( graph.neighbors(3).collect::<Vec<_>>(), vec![0, 1] );
+        assert_eq!( graph.neighbors(2).count(), 0 );
+        assert_eq!( graph.neighbors(10).count(), 0 );
+    }
+}
@@ -0,0 +1,108 @@
+use crate::*;
+use crate::graph::{Instant, TimeGraph};
+
+
+impl TimeGraph {
+
+    /// Builds a time graph from a DOT digraph
+    ///
+    /// Each node identifier should be of the form `t{n}` which refers
+    /// to the instant `n`. Each edge `ti -> tj` should be labelled with a time span
+    /// (see its [`core::str::FromStr`] implementation) which constrains
+    /// the duration from `ti` to `tj`, as in the following example:
+    /// ```text
+    /// digraph {
+    ///     t0 -> t1 [label="[1h,2h]"];
+    ///     t1 -> t2 [label="[30min,+oo["];
+    /// }
+    /// ```
+    /// The other statements (nodes, attributes...) are ignored.
+    ///
+    /// An error is returned if an edge is malformed or if
+    /// the constraints are inconsistent.
+    pub fn from_dot(s: &str) -> Result<TimeGraph, ParseError>
+    {
+        let body = s.find('{')
+            .and_then(|start| s.rfind('}').map(|end| (start, end)))
+            .filter(|(start, end)| start < end)
+            .map(|(start,end)| &s[start+1..end])
+            .ok_or_else(|| ParseError::new("DOT digraph body `{...}` expected"))?;
+
+        let constraints = body
+            .split([';', '\n'])
+            .map(str::trim)
+            .filter(|stmt| stmt.contains("->"))
+            .map(parse_dot_edge)
+            .collect::<Result<Vec<_>,_>>()?;
+
+        let mut graph = TimeGraph::default();
+        graph.extend(constraints)
+            .map_err(|_| ParseError::new("inconsistent time constraints"))?;
+        Ok(graph)
+    }
+}
+
+fn parse_dot_edge(stmt: &str) -> Result<((Instant,Instant), TimeSpan), ParseError>
+{
+    let (edge, attrs) = match stmt.split_once('[') {
+        Some((edge, attrs)) => (edge, attrs),
+        None => return Err(ParseError::new(format!("missing label for edge `{}`", stmt)))
+    };
+    let (from, to) = edge.split_once("->")
+        .ok_or_else(|| ParseError::new(format!("malformed edge `{}`", stmt)))?;
+
+    let label = attrs.find("label")
+        .map(|pos| attrs[pos+5..].trim_start())
+        .and_then(|s| s.strip_prefix('='))
+        .map(str::trim_start)
+        .and_then(|s| s.strip_prefix('"'))
+        .and_then(|s| s.split_once('"'))
+        .map(|(label,_)| label)
+        .ok_or_else(|| ParseError::new(format!("missing label for edge `{}`", stmt)))?;
+
+    Ok(((parse_dot_node(from)?, parse_dot_node(to)?), label.parse()?))
+}
+
+fn parse_dot_node(node: &str) -> Result<Instant, ParseError>
+{
+    let node = node.trim();
+    node.trim_matches('"')
+        .strip_prefix('t')
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| ParseError::new(format!("invalid instant `{}` (should be `t{{n}}`)", node)))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::graph::*;
+
+    #[test]
+    fn from_dot()
+    {
+        let graph = TimeGraph::from_dot(r#"
+            digraph constraints {
+                node [shape=circle];
+                t0 -> t1 [label="[1h,2h]"];
+                t1 -> t2 [label="[30min,+oo["] ; t0 -> t3 [color=red, label = "{10min}"]
+            }"#).unwrap();
+
+        assert_eq!( graph.size(), 4 );
+        assert_eq!( graph.timespan(0,1), TimeSpan::new(TimeValue::from_hours(1), TimeValue::from_hours(2)) );
+        assert_eq!( graph.timespan(0,2), TimeSpan::after(TimeValue::from_mins(90)) );
+        assert_eq!( graph.timespan(0,3), TimeSpan::singleton(TimeValue::from_mins(10)) );
+
+        assert!( TimeGraph::from_dot("digraph { }").unwrap().is_empty() );
+
+        assert!( TimeGraph::from_dot("t0 -> t1").is_err() );
+        assert!( TimeGraph::from_dot(r#"digraph { t0 -> t1; }"#).is_err() );
+        assert!( TimeGraph::from_dot(r#"digraph { a -> t1 [label="[1h,2h]"]; }"#).is_err() );
+        assert!( TimeGraph::from_dot(r#"digraph { t0 -> t1 [label="[1h,2"]; }"#).is_err() );
+        assert!( TimeGraph::from_dot(r#"digraph {
+            t0 -> t1 [label="[1h,2h]"];
+            t1 -> t2 [label="[1h,2h]"];
+            t0 -> t2 [label="[0,1h]"];
+        }"#).is_err() );
+    }
+}
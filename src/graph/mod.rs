@@ -1,7 +1,7 @@
 //! # Time constraints management
 
 /// # A graph of non disjunctive time constraints.
-
+///
 /// Each node  of the graph corresponds to an instant and
 /// the time constraints between two nodes are defined as a TimeInterval.
 /// Each added constraint is
@@ -62,12 +62,11 @@
 /// 1. U. Montanari. _"Networks of constraints: fundamental properties and applications to picture
 ///      processing"_, Information sciences 7, 1974, pp 95-132.
 /// 1. C.H. Papadimitriou and K. Steiglitz. _"Combinatorial optimization: algorithms and complexity."_
-/// 	Prentice-Hall, Englewood Cliffs, N.J. 1982.</li>
+///    Prentice-Hall, Englewood Cliffs, N.J. 1982.
 ///
 #[embed_doc_image("timegraph-1", "images/timegraph-1.png")]
 #[embed_doc_image("timegraph-2", "images/timegraph-2.png")]
 #[embed_doc_image("timegraph-3", "images/timegraph-3.png")]
-
 #[derive(Clone,Default)]
 pub struct TimeGraph {
     size : Instant,
@@ -86,7 +85,7 @@ pub struct TimeGraph {
 }
 
 
-use std::fmt;
+use core::fmt;
 use super::*;
 use embed_doc_image::embed_doc_image;
 
@@ -96,7 +95,10 @@ mod constraints;
 mod propagation;
 mod storage;
 mod scheduler;
+mod dot;
+mod bytes;
 pub use scheduler::TimeScheduler;
+pub use propagation::{TimePropagation, TimePropagationResult, TimeInconsistencyError};
 
 
 /// Index of an instant in the graph
@@ -311,7 +313,7 @@ impl<TW> TimeBounds for ((Instant, Instant), TW)
     pub fn merge(&mut self, mut rhs: TimeGraph) -> TimePropagationResult
     {
         if self.size < rhs.size {
-            std::mem::swap(self, &mut rhs)
+            core::mem::swap(self, &mut rhs)
         }
         let mut stgchanged = false;
         if self.size == rhs.size {
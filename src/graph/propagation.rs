@@ -1,8 +1,9 @@
-use std::error::Error;
-use std::fmt;
-use std::mem::swap;
+use core::error::Error;
+use core::fmt;
+use core::mem::swap;
+use alloc::vec::Vec;
 use crate::graph::{Instant, TimeConstraint, TimeGraph};
-use crate::TimeValue;
+use crate::{Timestamp, TimeSlot, TimeSpan, TimeValue};
 
 pub type TimePropagationResult = Result<TimePropagation,TimeInconsistencyError>;
 
@@ -51,12 +52,15 @@ impl TimeGraph
         } else {
             let max = k.from().max(k.to());
             if self.size() <= max {
-                // si i ou j n'était pas dans le graphe, on n'aura rien à propager
+                // si i ou j n'était pas dans le graphe, la contrainte est forcément consistante
+                // mais elle doit être propagée vers les autres instants (si l'un des deux existait)
                 self.resize(max + 1);
                 unsafe {
                     // SAFETY: we have just resize the graph for that
                     *self.lower_mut(k.from(), k.to()) = k.lower_bound();
                     *self.lower_mut(k.to(), k.from()) = -k.upper_bound();
+                    self.propagate_lower_bound(k.from(), k.to());
+                    self.propagate_lower_bound(k.to(), k.from());
                 }
                 Ok(TimePropagation::Propagated)
             } else {
@@ -98,6 +102,24 @@ impl TimeGraph
         }
     }
 
+    /// Constrains the instant `i` to be before (or at the same time as) the instant `j`
+    ///
+    /// This is the same as propagating the constraint `j - i` in `[0,+oo[`.
+    #[inline]
+    pub fn add_precedence(&mut self, i: Instant, j: Instant) -> TimePropagationResult
+    {
+        self.propagate(((i,j), TimeSpan::after(TimeValue::default())))
+    }
+
+    /// Constrains the instants `i` and `j` to occur at the same time
+    ///
+    /// This is the same as propagating the constraint `j - i` in `{0}`.
+    #[inline]
+    pub fn add_simultaneity(&mut self, i: Instant, j: Instant) -> TimePropagationResult
+    {
+        self.propagate(((i,j), TimeSpan::singleton(TimeValue::default())))
+    }
+
     /// Merge two timegraphs
     pub fn merge(&mut self, mut graph: TimeGraph) -> TimePropagationResult
     {
@@ -165,7 +187,7 @@ impl TimeGraph
                     if val > *x { *x = val; }
                 }
                 if unsafe { self.lower(i,i) }.is_strictly_positive() {
-                    self.size = 0;
+                    self.clear();
                     return Err(TimeInconsistencyError::Fatal)
                 }
             }
@@ -173,6 +195,60 @@ impl TimeGraph
         Ok(TimePropagation::Propagated)
     }
 
+    /// Add several constraints incrementally, the tightest first
+    ///
+    /// The constraints are sorted by width (the smallest first) before being
+    /// propagated one by one (see [`Self::propagate`]). Since a tight constraint
+    /// usually implies the looser ones, these last are then often already ensured
+    /// by the graph and their propagation is skipped, which reduces the total work.
+    ///
+    /// The resulting graph is the same minimal graph as the one obtained
+    /// with an unsorted order (or with [`Self::extend`]).
+    ///
+    /// If a constraint is inconsistent, the propagation stops and an error
+    /// is returned: the previously added constraints remain in the graph.
+    pub fn add_sorted<K:TimeConstraint>(&mut self, mut ks: Vec<K>) -> TimePropagationResult
+    {
+        ks.sort_by_key(|k| k.upper_bound() - k.lower_bound());
+        ks.into_iter()
+            .try_fold(TimePropagation::Unchanged, |result, k| {
+                match self.propagate(k)? {
+                    TimePropagation::Unchanged => Ok(result),
+                    TimePropagation::Propagated => Ok(TimePropagation::Propagated)
+                }
+            })
+    }
+
+    /// Tightens the constraints according to absolute time windows
+    ///
+    /// The instant `i` should occur within `domains[i]`. These absolute windows
+    /// are encoded by relative constraints from a virtual origin
+    /// (i.e. [`Timestamp::EPOCH`]), added as a temporary instant which is
+    /// removed after the propagation: only its impact on the constraints
+    /// between the instants remains. For instance, two instants in `[8h,10h]`
+    /// and `[12h,13h]` are then separated by at least 2h and at most 5h.
+    ///
+    /// The graph is resized if there are more domains than instants.
+    /// If the domains are inconsistent with the graph, an error is
+    /// returned and the graph is left unchanged.
+    pub fn tighten_with_domains(&mut self, domains: &[TimeSlot]) -> TimePropagationResult
+    {
+        let size = self.size().max(domains.len() as Instant);
+        let origin = size;
+        let mut graph = self.clone();
+        graph.resize(size + 1);
+        domains.iter()
+            .enumerate()
+            .try_for_each(|(i, tw)| graph.propagate(((origin, i as Instant), *tw - Timestamp::EPOCH)).map(|_| ()))?;
+        graph.resize(size);
+        if graph.data == self.data {
+            Ok(TimePropagation::Unchanged)
+        } else {
+            *self = graph;
+            Ok(TimePropagation::Propagated)
+        }
+    }
+
     /// Add several constraints in one shot
     ///
     /// If this set of constraints are inconsistent with the graph,
@@ -194,23 +270,175 @@ impl TimeGraph
                 }
             }
             _ => {
-                iter.for_each(|k| {
-                        let max = k.from().max(k.to());
-                        if max >= self.size() { self.resize(max+1) }
-
-                        let lower = unsafe { self.lower_mut(k.from(), k.to()) };
-                        if *lower < k.lower_bound() {
-                            *lower = k.lower_bound();
-                        }
-                        // SAFETY: if lower exists (checked just above), the upper does...
-                        let upper = unsafe { self.lower_mut(k.to(), k.from()) };
-                        if *upper < -k.upper_bound() {
-                            *upper = -k.upper_bound();
-                        }
-                    });
+                iter.for_each(|k| self.set_raw_bounds(k));
                 self.global_propagation()
             }
         }
     }
 
+    /// Add several constraints in one shot, with a known number of instants
+    ///
+    /// This is the same as [`Self::extend`] but the graph is resized only once,
+    /// to `max_instant+1` instants, before writing all the constraints. The
+    /// constraints are then propagated globally, even if there is only one of them.
+    ///
+    /// A constraint which refers to an instant after `max_instant` is still
+    /// handled (the graph is then resized again).
+    ///
+    /// If this set of constraints are inconsistent with the graph,
+    /// there is no possible recovery and the graph is definitively corrupted
+    pub fn extend_presized<I,K>(&mut self, max_instant: Instant, iter:I) -> TimePropagationResult
+        where
+            K: TimeConstraint,
+            I: IntoIterator<Item=K>
+    {
+        if max_instant >= self.size() { self.resize(max_instant+1) }
+        iter.into_iter().for_each(|k| self.set_raw_bounds(k));
+        self.global_propagation()
+    }
+
+    /// Writes the bounds of a constraint without any propagation
+    fn set_raw_bounds<K:TimeConstraint>(&mut self, k: K)
+    {
+        let max = k.from().max(k.to());
+        if max >= self.size() { self.resize(max+1) }
+
+        let lower = unsafe { self.lower_mut(k.from(), k.to()) };
+        if *lower < k.lower_bound() {
+            *lower = k.lower_bound();
+        }
+        // SAFETY: if lower exists (checked just above), the upper does...
+        let upper = unsafe { self.lower_mut(k.to(), k.from()) };
+        if *upper < -k.upper_bound() {
+            *upper = -k.upper_bound();
+        }
+    }
+
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::graph::*;
+
+    #[test]
+    fn precedence()
+    {
+        use core::cmp::Ordering;
+        let mut graph = TimeGraph::with_size(4);
+        assert_eq!( Ok(TimePropagation::Propagated), graph.add_precedence(0, 1));
+        assert_eq!( Ok(TimePropagation::Unchanged), graph.add_precedence(0, 1));
+        assert_eq!( graph.timespan(0,1), TimeSpan::after(TimeValue::default()));
+        assert_ne!( graph.instant_cmp(0,1), Some(Ordering::Greater));
+        assert!( !graph.are_distinct_instants(0,1));
+
+        // with a strict delay, the order is known
+        graph.propagate(((1,2), TimeSpan::after(TimeValue::from_secs(1)))).unwrap();
+        assert_eq!( graph.instant_cmp(0,2), Some(Ordering::Less));
+        assert_eq!( graph.instant_cmp(2,0), Some(Ordering::Greater));
+        assert!( graph.are_distinct_instants(0,2));
+
+        assert_eq!( Ok(TimePropagation::Propagated), graph.add_simultaneity(2, 3));
+        assert_eq!( graph.instant_cmp(2,3), Some(Ordering::Equal));
+        assert_eq!( graph.instant_cmp(0,3), Some(Ordering::Less));
+        assert!( !graph.are_distinct_instants(2,3));
+
+        // both precedences lead to a simultaneity
+        assert_eq!( Ok(TimePropagation::Propagated), graph.add_precedence(1, 0));
+        assert_eq!( graph.instant_cmp(0,1), Some(Ordering::Equal));
+
+        // a cycle of precedence with a strict delay is inconsistent
+        assert_eq!( Err(TimeInconsistencyError::Recovered), graph.add_precedence(2, 0));
+        assert_eq!( Err(TimeInconsistencyError::Recovered), graph.add_simultaneity(0, 3));
+    }
+
+    #[test]
+    fn extend_presized()
+    {
+        let t = TimeValue::from_secs;
+        let constraints = (0..50)
+            .map(|i| ((i, i+1), TimeSpan::new(t(1), t(i as i64 + 2))))
+            .chain([((0, 50), TimeSpan::before(t(60)))])
+            .collect::<Vec<_>>();
+
+        let mut expected = TimeGraph::default();
+        expected.extend(constraints.iter().copied()).unwrap();
+
+        let mut graph = TimeGraph::default();
+        assert_eq!( Ok(TimePropagation::Propagated), graph.extend_presized(50, constraints.iter().copied()));
+        assert_eq!( graph, expected );
+        assert_eq!( graph.size(), 51 );
+        assert_eq!( graph.timespan(0, 50), TimeSpan::new(t(50), t(60)) );
+
+        // an underestimated size is still handled
+        let mut graph = TimeGraph::default();
+        graph.extend_presized(10, constraints.iter().copied()).unwrap();
+        assert_eq!( graph, expected );
+    }
+
+    #[test]
+    fn tighten_with_domains()
+    {
+        let h = TimeValue::from_hours;
+        let slot = |a, b| TimeSlot::new(Timestamp::from_origin(h(a)), Timestamp::from_origin(h(b)));
+
+        let mut graph = TimeGraph::with_size(2);
+        graph.propagate(((0,1), TimeSpan::after(h(1)))).unwrap();
+        assert_eq!( Ok(TimePropagation::Propagated), graph.tighten_with_domains(&[slot(8,10), slot(12,13), slot(0,1)]));
+        assert_eq!( graph.size(), 3 );
+        assert_eq!( graph.timespan(0,1), TimeSpan::new(h(2), h(5)) );
+        assert_eq!( graph.timespan(0,2), TimeSpan::new(-h(10), -h(7)) );
+        assert_eq!( Ok(TimePropagation::Unchanged), graph.tighten_with_domains(&[slot(8,10)]));
+
+        // t1 should be at least 1h after t0
+        let before = graph.clone();
+        assert_eq!( Err(TimeInconsistencyError::Recovered), graph.tighten_with_domains(&[slot(8,10), slot(5,6)]));
+        assert_eq!( graph.timespan(0,1), before.timespan(0,1) );
+        assert_eq!( graph.size(), before.size() );
+    }
+
+    #[test]
+    fn propagate_to_new_instant()
+    {
+        let t = TimeValue::from_secs;
+        let mut graph = TimeGraph::with_size(2);
+        graph.propagate(((0,1), TimeSpan::new(t(1), t(2)))).unwrap();
+
+        // the new instant t3 is constrained with t1, so it is with t0
+        assert_eq!( Ok(TimePropagation::Propagated), graph.propagate(((1,3), TimeSpan::singleton(t(1)))));
+        assert_eq!( graph.size(), 4 );
+        assert_eq!( graph.timespan(0,3), TimeSpan::new(t(2), t(3)) );
+        assert_eq!( graph.timespan(3,0), TimeSpan::new(-t(3), -t(2)) );
+        assert_eq!( graph.timespan(2,3), TimeSpan::all() );
+
+        // the same from the new instant
+        assert_eq!( Ok(TimePropagation::Propagated), graph.propagate(((5,0), TimeSpan::after(t(4)))));
+        assert_eq!( graph.timespan(5,3), TimeSpan::after(t(6)) );
+    }
+
+    #[test]
+    fn add_sorted()
+    {
+        let t = TimeValue::from_ticks;
+        let ks = vec![
+            ((0,1), TimeSpan::new(t(0), t(50))),
+            ((1,2), TimeSpan::new(t(7), t(10))),
+            ((0,2), TimeSpan::new(t(10), t(25))),
+            ((2,3), TimeSpan::after(t(1))),
+            ((0,3), TimeSpan::new(t(12), t(14))),
+        ];
+
+        let mut sorted = TimeGraph::default();
+        assert_eq!( sorted.add_sorted(ks.clone()), Ok(TimePropagation::Propagated) );
+        assert_eq!( sorted.add_sorted(ks.clone()), Ok(TimePropagation::Unchanged) );
+
+        let mut extended = TimeGraph::default();
+        extended.extend(ks).unwrap();
+
+        assert_eq!( sorted.size(), extended.size() );
+        (0..sorted.size()).for_each(|i| (0..sorted.size()).for_each(|j| {
+            assert_eq!( sorted.timespan(i,j), extended.timespan(i,j) );
+        }));
+    }
 }
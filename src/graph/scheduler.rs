@@ -1,10 +1,11 @@
-use std::fmt;
-use std::iter;
-use std::ops::BitAndAssign;
+use core::fmt;
+use core::iter;
+use core::ops::BitAndAssign;
+use alloc::collections::VecDeque;
 use crate::*;
 use crate::graph::*;
 use crate::graph::propagation::{TimeInconsistencyError, TimePropagation, TimePropagationResult};
-use crate::iter::{TimeIntersection, TimeTranslation};
+use crate::iter::{TimeConvexIterator, TimeIntersection, TimeTranslation};
 
 
 /// # A manager of constrained time variables.
@@ -24,14 +25,33 @@ pub struct TimeScheduler<'a> {
 
 impl TimeScheduler<'_> {
 
-    pub fn new(graph: &TimeGraph) -> TimeScheduler
+    pub fn new(graph: &TimeGraph) -> TimeScheduler<'_>
     {
         TimeScheduler {
             constraints: graph,
-            schedule: iter::repeat(TimeSlots::all()).take(graph.size() as usize).collect()
+            schedule: iter::repeat_n(TimeSlots::all(), graph.size() as usize).collect()
         }
     }
 
+    /// Rebinds this scheduler to another constraint graph
+    ///
+    /// The current schedulings are kept and propagated through the constraints
+    /// of `graph` (see [`Self::propagate_all`]), so the narrowings done so far are
+    /// preserved when they are still feasible. Typically, `graph` is a tightened
+    /// version of the current constraints.
+    ///
+    /// The instants which are added by `graph` are not scheduled yet and the
+    /// schedulings of the instants which are out of `graph` are dropped.
+    /// If the schedulings are inconsistent with `graph`, an error is returned.
+    pub fn rebind(self, graph: &TimeGraph) -> Result<TimeScheduler<'_>, TimeInconsistencyError>
+    {
+        let mut schedule = self.schedule.into_vec();
+        schedule.resize(graph.size() as usize, TimeSlots::all());
+        let mut agenda = TimeScheduler { constraints: graph, schedule: schedule.into_boxed_slice() };
+        agenda.propagate_all()?;
+        Ok(agenda)
+    }
+
     #[inline]
     pub fn constraints(&self) -> &'_ TimeGraph { self.constraints }
 
@@ -41,6 +61,44 @@ impl TimeScheduler<'_> {
     #[inline]
     pub fn schedule(&self) -> &[TimeSlots] { &self.schedule }
 
+    /// Iterates over the instants with their current scheduling
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item=(Instant, &TimeSlots)> + '_
+    {
+        (0..).zip(self.schedule.iter())
+    }
+
+    /// Iterates over the instants which are already fixed to a single timestamp
+    #[inline]
+    pub fn singletons(&self) -> impl Iterator<Item=(Instant, Timestamp)> + '_
+    {
+        self.iter()
+            .filter(|(_, tw)| tw.is_singleton())
+            .map(|(i, tw)| (i, tw.lower_bound()))
+    }
+
+    /// The total duration of the possible timestamps of an instant
+    ///
+    /// This is the sum of the durations of the parts of its scheduling, so it
+    /// measures how much the instant is constrained (the gaps are not counted,
+    /// see [`Self::slack`]). It is infinite if the scheduling is unbounded.
+    pub fn flexibility(&self, i: Instant) -> TimeValue
+    {
+        assert![(i as usize) < self.schedule.len(), "index out of bounds"];
+        (&self.schedule[i as usize]).into_iter().map(|tw| tw.duration()).sum()
+    }
+
+    /// The delay between the earliest and the latest timestamps of an instant
+    ///
+    /// This is the duration of the convex envelope of its scheduling
+    /// (it is infinite if the scheduling is unbounded and zero if it is empty).
+    #[inline]
+    pub fn slack(&self, i: Instant) -> TimeValue
+    {
+        assert![(i as usize) < self.schedule.len(), "index out of bounds"];
+        self.schedule[i as usize].convex_envelope().duration()
+    }
+
     /// The minimum of the upper bounds of each scheduling
     pub fn latest_beginning(&self) -> Timestamp {
         self.schedule.iter().map(|i| i.upper_bound()).min().unwrap()
@@ -118,11 +176,184 @@ impl TimeScheduler<'_> {
         where
             TW::Output: TimeContaining<TimeSlots> + TimeOverlapping<TimeSlots> + TimeWindow<TimePoint=Timestamp>,
             TimeSlots: BitAndAssign<TW::Output>,
-            TW: std::ops::Not
+            TW: core::ops::Not
     {
         self.retain(i, !tw)
     }
 
+    /// Constrains an instant to occur within a maximal delay after another one
+    ///
+    /// The scheduling of `j` is narrowed to the scheduling of `i` translated by
+    /// `[0,max]` (and conversely, the scheduling of `i` keeps only the timestamps
+    /// compatible with the remaining ones of `j`), then the narrowings are propagated.
+    /// The shared constraint graph is not modified.
+    ///
+    /// If the current schedulings make this constraint impossible,
+    /// an error is returned and this scheduler is left unchanged.
+    pub fn tighten_duration(&mut self, i: Instant, j: Instant, max: TimeValue) -> TimePropagationResult
+    {
+        assert![(i as usize) < self.schedule.len() && (j as usize) < self.schedule.len(), "index out of bounds"];
+        let delay = TimeSpan::new(TimeValue::default(), max);
+        let after_i = self.schedule[i as usize].iter()
+            .translation(&delay)
+            .collect::<TimeSlots>();
+        let result = self.retain(j, after_i)?;
+        let before_j = self.schedule[j as usize].iter()
+            .translation(&-delay)
+            .collect::<TimeSlots>();
+        // each remaining timestamp of j comes from one of i, so this narrowing succeeds
+        match self.retain(i, before_j)? {
+            TimePropagation::Unchanged => Ok(result),
+            TimePropagation::Propagated => Ok(TimePropagation::Propagated)
+        }
+    }
+
+    /// Explains why narrowing the scheduling of an instant would fail
+    ///
+    /// The propagation of the narrowing `tw` of the instant `i` is simulated
+    /// on a copy of the schedulings (so this scheduler is not modified) until
+    /// a fixpoint. Each time the propagation along a constraint `(h,j)` leaves
+    /// no room to the instant `j`, this constraint is returned (and `j` is no
+    /// longer propagated). If the narrowing itself leaves no room to `i`,
+    /// then `[(i,i)]` is returned.
+    ///
+    /// An empty result means that the narrowing is consistent.
+    pub fn explain_conflict(&self, i: Instant, tw: &TimeSlots) -> Vec<(Instant, Instant)>
+    {
+        assert![(i as usize) < self.schedule.len(), "index out of bounds"];
+        let mut schedule = self.schedule.clone();
+        schedule[i as usize] &= tw;
+        if schedule[i as usize].is_empty() {
+            return vec![(i,i)];
+        }
+        let mut conflicts = Vec::new();
+        let mut pending = VecDeque::from([i]);
+        while let Some(h) = pending.pop_front() {
+            // an emptied scheduling is not propagated any further
+            if schedule[h as usize].is_empty() { continue; }
+            for k in self.constraints.constraints_from(h) {
+                let j = k.to();
+                if schedule[j as usize].is_empty() { continue; }
+                let narrowed = schedule[j as usize].iter()
+                    .intersection(schedule[h as usize].iter().translation(&TimeInterval::from(k)))
+                    .collect::<TimeSlots>();
+                if narrowed.is_empty() {
+                    conflicts.push((h,j));
+                }
+                if narrowed != schedule[j as usize] {
+                    schedule[j as usize] = narrowed;
+                    if !pending.contains(&j) { pending.push_back(j); }
+                }
+            }
+        }
+        conflicts
+    }
+
+    /// Computes the earliest schedule
+    ///
+    /// Each instant is fixed, one after the other, to its earliest possible
+    /// timestamp and this choice is propagated to the other ones.
+    /// Since the constraint graph is minimal, the propagation always succeeds
+    /// if the scheduling of each instant is convex but, in the general case,
+    /// the consistency is checked at each step.
+    ///
+    /// Returns `None` if an instant has no finite earliest timestamp
+    /// or if the earliest choices lead to an inconsistency.
+    pub fn solve_earliest(&self) -> Option<Vec<Timestamp>>
+    {
+        let mut agenda = self.clone();
+        (0..self.schedule.len() as Instant)
+            .map(|i| agenda.commit(i))
+            .collect()
+    }
+
+    /// Fixes an instant to its earliest possible timestamp
+    ///
+    /// The instant `i` is narrowed to the lower bound of its current scheduling
+    /// and this choice is propagated to the other instants (see [`Self::retain`]).
+    /// This is the labelling step of a search: the chosen timestamp is returned
+    /// (if the instant was already fixed, its timestamp is returned and nothing changes).
+    ///
+    /// Returns `None` if the scheduling has no finite earliest timestamp
+    /// (then this scheduler is left unchanged).
+    pub fn commit(&mut self, i: Instant) -> Option<Timestamp>
+    {
+        assert![(i as usize) < self.schedule.len(), "index out of bounds"];
+        let t = self.schedule[i as usize].lower_bound();
+        if t.is_finite() && self.retain(i, t).is_ok() { Some(t) } else { None }
+    }
+
+    /// Exports the narrowed schedulings as relative constraints
+    ///
+    /// For each couple of instants `(i,j)`, the delay from `i` to `j` is
+    /// constrained by the convex envelopes of their schedulings (i.e. between
+    /// the earliest `j` minus the latest `i` and the latest `j` minus the earliest `i`).
+    /// These constraints are added to the constraints of this scheduler and
+    /// the returned graph is propagated.
+    ///
+    /// If a scheduling is empty or if the propagation of these constraints
+    /// fails, an error is returned (this scheduler is not modified anyway).
+    pub fn as_graph(&self) -> Result<TimeGraph, TimeInconsistencyError>
+    {
+        if self.schedule.iter().any(|tw| tw.is_empty()) {
+            return Err(TimeInconsistencyError::Recovered);
+        }
+        let mut graph = self.constraints.clone();
+        let delay = |a: Timestamp, b: Timestamp, inf: TimeValue| {
+            if a.is_finite() && b.is_finite() { a - b } else { inf }
+        };
+        let n = self.schedule.len() as Instant;
+        let ks = (0..n)
+            .flat_map(|i| (i+1..n).map(move |j| (i,j)))
+            .map(|(i,j)| {
+                let (si, sj) = (&self.schedule[i as usize], &self.schedule[j as usize]);
+                let lower = delay(sj.lower_bound(), si.upper_bound(), -TimeValue::INFINITE);
+                let upper = delay(sj.upper_bound(), si.lower_bound(), TimeValue::INFINITE);
+                ((i,j), TimeSpan::new(lower, upper))
+            })
+            .filter(|(_, k)| !k.is_all())
+            .collect::<Vec<_>>();
+        graph.extend(ks)?;
+        Ok(graph)
+    }
+
+    /// Propagates the scheduling of all the instants until a fixpoint
+    ///
+    /// Each scheduling is intersected with the schedulings of the other
+    /// instants translated by their constraints, until no scheduling changes.
+    /// This is useful after modifying several schedulings at once, since
+    /// [`Self::retain`] only propagates from the narrowed instant.
+    ///
+    /// If a scheduling becomes empty, an error is returned and this
+    /// scheduler is left unchanged.
+    pub fn propagate_all(&mut self) -> TimePropagationResult
+    {
+        let mut schedule = self.schedule.clone();
+        let mut result = TimePropagation::Unchanged;
+        loop {
+            let mut changed = false;
+            for i in 0..schedule.len() as Instant {
+                for k in self.constraints.constraints_from(i) {
+                    let j = k.to() as usize;
+                    let narrowed = schedule[j].iter()
+                        .intersection(schedule[i as usize].iter().translation(&TimeInterval::from(k)))
+                        .collect::<TimeSlots>();
+                    if narrowed.is_empty() {
+                        return Err(TimeInconsistencyError::Recovered);
+                    }
+                    if narrowed != schedule[j] {
+                        schedule[j] = narrowed;
+                        changed = true;
+                    }
+                }
+            }
+            if !changed { break; }
+            result = TimePropagation::Propagated;
+        }
+        self.schedule = schedule;
+        Ok(result)
+    }
+
     fn propagate_scheduling(&mut self, i: Instant)
     {
         debug_assert!( i as usize <= self.schedule.len() );
@@ -131,12 +362,11 @@ impl TimeScheduler<'_> {
                 .constraints_from(i)
                 .for_each(|k| {
                     let j = k.to() as usize;
-                    *self.schedule.get_unchecked_mut(j) =
-                        self.schedule.get_unchecked(j).iter()
-                            .intersection(self.schedule.get_unchecked(i as usize)
-                                .iter()
-                                .translation(&TimeInterval::from(k)))
-                            .collect();
+                    self.schedule.get_unchecked(j).iter()
+                        .intersection(self.schedule.get_unchecked(i as usize)
+                            .iter()
+                            .translation(&TimeInterval::from(k)))
+                        .collect_into_set(self.schedule.get_unchecked_mut(j));
                 });
         }
     }
@@ -157,6 +387,7 @@ impl fmt::Display for TimeScheduler<'_> {
     }
 }
 
+#[cfg(feature = "std")]
 impl TimeSetFormat for TimeScheduler<'_>
 {
     fn format_timeset(&self, timefmt: &str) -> String {
@@ -173,6 +404,8 @@ pub mod tests {
     use crate::graph::*;
     use crate::graph::propagation::TimePropagation::*;
     use crate::graph::TimeScheduler;
+    use crate::graph::propagation::TimeInconsistencyError;
+    use crate::testing::h;
 
     #[test]
     fn propagation()
@@ -190,4 +423,212 @@ pub mod tests {
         assert_eq!( Ok(Propagated), agenda.set_deadline(Timestamp::from_origin(TimeValue::from_days(2))));
 
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn explain_conflict()
+    {
+        let m = |n| Timestamp::from_origin(TimeValue::from_mins(n));
+        let mut g = TimeGraph::with_size(3);
+        g.propagate(((0,1), TimeValue::from_hours(1) ..= TimeValue::from_hours(2))).unwrap();
+        g.propagate(((1,2), TimeValue::from_hours(1) ..= TimeValue::from_hours(2))).unwrap();
+        g.propagate(((0,2), TimeValue::from_hours(2) ..= TimeValue::from_hours(3))).unwrap();
+
+        let mut agenda = TimeScheduler::new(&g);
+        assert_eq!( Ok(Propagated), agenda.retain(1, TimeSlot::singleton(m(525)) | TimeSlot::singleton(m(600))));
+        assert_eq!( Ok(Propagated), agenda.retain(2, TimeSlot::new(m(600), m(645)) | TimeSlot::new(m(690), m(720))));
+        assert_eq!( agenda.scheduling(0), Some(&(TimeSlot::new(m(420), m(465)) | TimeSlot::new(m(480), m(540)))));
+        let schedule = agenda.schedule().to_vec();
+
+        // consistent narrowings
+        assert!( agenda.explain_conflict(0, &TimeSlot::singleton(m(525)).into()).is_empty() );
+        assert!( agenda.explain_conflict(0, &TimeSlots::all()).is_empty() );
+
+        // t0 is already in [7h,7h45]U[8h,9h]
+        assert_eq!( agenda.explain_conflict(0, &TimeSlot::new(m(300), m(400)).into()), vec![(0,0)] );
+
+        // t0 at 8h leaves room to t1 (at 10h) and to t2 (in [10h,10h45]),
+        // but t1 at 10h leaves no room to t2 (in [11h,12h])
+        assert_eq!( agenda.explain_conflict(0, &TimeSlot::singleton(m(480)).into()), vec![(1,2)] );
+        // (which is only detected by a full propagation)
+        let mut probe = agenda.clone();
+        assert_eq!( Ok(Propagated), probe.retain(0, TimeSlot::singleton(m(480))));
+        assert_eq!( Err(TimeInconsistencyError::Recovered), probe.propagate_all());
+
+        // the scheduler is not modified
+        assert_eq!( agenda.schedule(), &schedule[..] );
+    }
+
+    #[test]
+    fn as_graph()
+    {
+        let mut g = TimeGraph::with_size(3);
+        g.propagate(((0,1), TimeValue::from_hours(1) ..= TimeValue::from_hours(4))).unwrap();
+
+        let mut agenda = TimeScheduler::new(&g);
+        agenda.retain(0, TimeSlot::new(h(8), h(9))).unwrap();
+        agenda.retain(2, TimeSlot::new(h(12), h(14))).unwrap();
+
+        let narrowed = agenda.as_graph().unwrap();
+        assert_eq!( narrowed.size(), 3 );
+        assert_eq!( narrowed.timespan(0,1), TimeSpan::new(TimeValue::from_hours(1), TimeValue::from_hours(4)));
+        assert_eq!( narrowed.timespan(0,2), TimeSpan::new(TimeValue::from_hours(3), TimeValue::from_hours(6)));
+        assert_eq!( narrowed.timespan(1,2), TimeSpan::new(-TimeValue::from_hours(1), TimeValue::from_hours(5)));
+
+        // no narrowing, no new constraint
+        let agenda = TimeScheduler::new(&g);
+        assert_eq!( agenda.as_graph().map(|g| g.timespan(0,2)), Ok(TimeSpan::all()));
+    }
+
+    #[test]
+    fn propagate_all()
+    {
+        let mut g = TimeGraph::with_size(3);
+        g.propagate(((0,1), TimeValue::from_hours(1) ..= TimeValue::from_hours(2))).unwrap();
+        g.propagate(((1,2), TimeValue::from_hours(1) ..= TimeValue::from_hours(1))).unwrap();
+
+        let mut agenda = TimeScheduler::new(&g);
+        assert_eq!( Ok(Unchanged), agenda.propagate_all());
+
+        // external partial schedule, not yet propagated
+        agenda.schedule[0] = TimeSlot::new(h(8), h(10)).into();
+        agenda.schedule[2] = TimeSlot::new(h(12), h(13)).into();
+        assert_eq!( Ok(Propagated), agenda.propagate_all());
+        assert_eq!( agenda.scheduling(0).unwrap(), &TimeSlot::new(h(9), h(10)));
+        assert_eq!( agenda.scheduling(1).unwrap(), &TimeSlot::new(h(11), h(12)));
+        assert_eq!( agenda.scheduling(2).unwrap(), &TimeSlot::new(h(12), h(13)));
+        assert_eq!( Ok(Unchanged), agenda.propagate_all());
+
+        // inconsistent schedule
+        agenda.schedule[1] = TimeSlot::new(h(5), h(6)).into();
+        assert_eq!( Err(TimeInconsistencyError::Recovered), agenda.propagate_all());
+        assert_eq!( agenda.scheduling(0).unwrap(), &TimeSlot::new(h(9), h(10)));
+    }
+
+    #[test]
+    fn tighten_duration()
+    {
+        let g = TimeGraph::with_size(3);
+
+        let mut agenda = TimeScheduler::new(&g);
+        agenda.retain(0, TimeSlot::new(h(8), h(10))).unwrap();
+        agenda.retain(1, TimeSlot::new(h(5), h(9)) | TimeSlot::new(h(20), h(22))).unwrap();
+        assert_eq!( Ok(Propagated), agenda.tighten_duration(0, 1, TimeValue::from_hours(2)));
+        assert_eq!( agenda.scheduling(1).unwrap(), &TimeSlot::new(h(8), h(9)));
+        assert_eq!( agenda.scheduling(0).unwrap(), &TimeSlot::new(h(8), h(9)));
+        assert_eq!( Ok(Unchanged), agenda.tighten_duration(0, 1, TimeValue::from_hours(2)));
+        assert!( g.timespan(0,1).is_all() );
+
+        // t2 is too late
+        agenda.retain(2, TimeSlot::new(h(12), h(14))).unwrap();
+        assert_eq!( Err(TimeInconsistencyError::Recovered), agenda.tighten_duration(0, 2, TimeValue::from_hours(2)));
+        assert_eq!( agenda.scheduling(2).unwrap(), &TimeSlot::new(h(12), h(14)));
+    }
+
+    #[test]
+    fn rebind()
+    {
+        let mut g = TimeGraph::with_size(2);
+        g.propagate(((0,1), TimeValue::from_hours(1) ..= TimeValue::from_hours(5))).unwrap();
+
+        let mut agenda = TimeScheduler::new(&g);
+        agenda.retain(0, TimeSlot::new(h(8), h(10))).unwrap();
+        assert_eq!( agenda.scheduling(1).unwrap(), &TimeSlot::new(h(9), h(15)));
+
+        // the tightened graph has a new instant
+        let mut tighter = g.clone();
+        tighter.propagate(((0,1), TimeValue::from_hours(2) ..= TimeValue::from_hours(3))).unwrap();
+        tighter.propagate(((1,2), TimeValue::from_hours(1) ..= TimeValue::from_hours(1))).unwrap();
+
+        let agenda = agenda.clone().rebind(&tighter).unwrap();
+        assert_eq!( agenda.schedule().len(), 3 );
+        assert_eq!( agenda.scheduling(0).unwrap(), &TimeSlot::new(h(8), h(10)));
+        assert_eq!( agenda.scheduling(1).unwrap(), &TimeSlot::new(h(10), h(13)));
+        assert_eq!( agenda.scheduling(2).unwrap(), &TimeSlot::new(h(11), h(14)));
+
+        // the kept narrowing is not feasible anymore
+        let mut agenda = TimeScheduler::new(&g);
+        agenda.retain(1, TimeSlot::new(h(8), h(9))).unwrap();
+        agenda.retain(0, TimeSlot::new(h(7), h(8))).unwrap();
+        let mut late = g.clone();
+        late.propagate(((0,1), TimeValue::from_hours(4) ..= TimeValue::from_hours(5))).unwrap();
+        assert_eq!( Err(TimeInconsistencyError::Recovered), agenda.rebind(&late).map(|_| ()));
+    }
+
+    #[test]
+    fn commit()
+    {
+        let mut g = TimeGraph::with_size(3);
+        g.propagate(((0,1), TimeValue::from_hours(1) ..= TimeValue::from_hours(2))).unwrap();
+
+        let mut agenda = TimeScheduler::new(&g);
+        assert_eq!( agenda.commit(0), None );
+        assert!( agenda.scheduling(0).unwrap().is_all() );
+
+        agenda.retain(0, TimeSlot::new(h(8), h(10))).unwrap();
+        assert_eq!( agenda.commit(1), Some(h(9)) );
+        assert_eq!( agenda.scheduling(1).unwrap(), &TimeSlots::singleton(h(9)));
+        assert_eq!( agenda.scheduling(0).unwrap(), &TimeSlot::new(h(8), h(8)));
+
+        // already fixed
+        let fixed = agenda.clone();
+        assert_eq!( agenda.commit(0), Some(h(8)) );
+        assert_eq!( agenda.schedule(), fixed.schedule() );
+        assert_eq!( agenda.singletons().count(), 2 );
+    }
+
+    #[test]
+    fn flexibility()
+    {
+        let g = TimeGraph::with_size(3);
+        let mut agenda = TimeScheduler::new(&g);
+        agenda.retain(0, TimeSlot::new(h(8), h(10)) | TimeSlot::new(h(14), h(15))).unwrap();
+        agenda.retain(1, h(9)).unwrap();
+
+        assert_eq!( agenda.flexibility(0), TimeValue::from_hours(3));
+        assert_eq!( agenda.slack(0), TimeValue::from_hours(7));
+        assert_eq!( agenda.flexibility(1), TimeValue::default());
+        assert_eq!( agenda.slack(1), TimeValue::default());
+        assert_eq!( agenda.flexibility(2), TimeValue::INFINITE);
+        assert_eq!( agenda.slack(2), TimeValue::INFINITE);
+    }
+
+    #[test]
+    fn iter()
+    {
+        let mut g = TimeGraph::with_size(3);
+        g.propagate(((0,1), TimeValue::from_hours(1) ..= TimeValue::from_hours(1))).unwrap();
+
+        let mut agenda = TimeScheduler::new(&g);
+        assert_eq!( agenda.iter().count(), 3 );
+        assert_eq!( agenda.singletons().count(), 0 );
+
+        agenda.retain(0, h(8)).unwrap();
+        agenda.retain(2, TimeSlot::new(h(10), h(12))).unwrap();
+        assert_eq!( agenda.iter().map(|(i,_)| i).collect::<Vec<_>>(), vec![0, 1, 2] );
+        assert_eq!( agenda.iter().nth(2), Some((2, &TimeSlot::new(h(10), h(12)).into())) );
+        assert_eq!( agenda.singletons().collect::<Vec<_>>(), vec![(0, h(8)), (1, h(9))] );
+    }
+
+    #[test]
+    fn solve_earliest()
+    {
+        let mut g = TimeGraph::with_size(3);
+        g.propagate(((0,1), TimeValue::from_hours(1) ..= TimeValue::from_hours(2))).unwrap();
+        g.propagate(((0,2), TimeValue::from_hours(3) ..= TimeValue::from_hours(5))).unwrap();
+        g.propagate(((1,2), TimeValue::from_hours(3) ..= TimeValue::from_hours(4))).unwrap();
+
+        let mut agenda = TimeScheduler::new(&g);
+        assert_eq!( agenda.solve_earliest(), None );
+
+        agenda.set_startline(h(0)).unwrap();
+        assert_eq!( agenda.solve_earliest(), Some(vec![h(0), h(1), h(4)]) );
+
+        // the earliest t1 is not compatible with t0 = 8h
+        agenda.retain(1, TimeSlot::new(h(8), h(8)) | TimeSlot::new(h(10), h(12))).unwrap();
+        assert_eq!( agenda.solve_earliest(), Some(vec![h(6), h(8), h(11)]) );
+
+        // the scheduler itself is unchanged
+        assert_eq!( agenda.scheduling(0).unwrap().lower_bound(), h(6) );
+        assert!( !agenda.scheduling(0).unwrap().is_singleton() );
+    }
+}
@@ -1,4 +1,5 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
 use super::*;
 
 impl TimeGraph {
@@ -29,6 +30,23 @@ impl TimeGraph {
     #[inline]
     pub fn size(&self) -> u32 { self.size }
 
+    /// Checks if the graph has no instant (node)
+    ///
+    /// Notice that this is the case after a fatal propagation error.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.size == 0 }
+
+    /// Removes all the instants (and so, all the constraints)
+    ///
+    /// The graph becomes empty, as the default one, but its
+    /// allocated memory is kept for further reuse.
+    #[inline]
+    pub fn clear(&mut self)
+    {
+        self.data.clear();
+        self.size = 0;
+    }
+
     /// Resize the graph
     ///
     /// If the new size is smaller than the current one,
@@ -109,6 +127,55 @@ impl TimeGraph {
         }
     }
 
+    /// Extracts the subgraph over some instants
+    ///
+    /// The instant `keep[a]` of this graph becomes the instant `a` of the
+    /// returned graph, and the propagated constraints between the kept instants
+    /// are copied. Since this graph is already minimal, the projection is
+    /// also minimal and no propagation is needed.
+    ///
+    /// An instant out of this graph is kept as an unconstrained one.
+    pub fn project(&self, keep: &[Instant]) -> TimeGraph
+    {
+        let mut graph = TimeGraph::with_size(keep.len() as Instant);
+        for (a,&i) in keep.iter().enumerate() {
+            for (b,&j) in keep.iter().enumerate() {
+                if a != b && i < self.size() && j < self.size() {
+                    // SAFETY: the indices are checked just above
+                    unsafe { *graph.lower_mut(a as Instant, b as Instant) = self.lower(i,j); }
+                }
+            }
+        }
+        graph
+    }
+
+    /// Merges two simultaneous instants
+    ///
+    /// The instants `keep` and `drop` should be constrained to occur at the same
+    /// time (see [`Self::instant_cmp`]). Since the graph is minimal, they then
+    /// have the same constraints with the other instants, so `drop` is just removed
+    /// and no propagation is needed. To avoid shifting all the indices, the last
+    /// instant takes the index of `drop` (so, if `keep` is the last one, it is
+    /// renumbered to `drop`) and the size of the graph decreases by one.
+    ///
+    /// If the instants are not simultaneous (or out of the graph), an error is
+    /// returned and the graph is left unchanged.
+    pub fn contract(&mut self, keep: Instant, drop: Instant) -> TimePropagationResult
+    {
+        if self.instant_cmp(keep, drop) != Some(Ordering::Equal) {
+            Err(TimeInconsistencyError::Recovered)
+        } else if keep == drop {
+            Ok(TimePropagation::Unchanged)
+        } else {
+            let last = self.size() - 1;
+            let instants = (0..last)
+                .map(|i| if i == drop { last } else { i })
+                .collect::<Vec<_>>();
+            *self = self.project(&instants);
+            Ok(TimePropagation::Propagated)
+        }
+    }
+
     pub fn instant_cmp(&self, i:Instant, j:Instant) -> Option<Ordering>
     {
         if i >= self.size() || j >= self.size() {
@@ -130,6 +197,111 @@ impl TimeGraph {
         }
     }
 
+    /// Lists the constraints which differ from another graph
+    ///
+    /// Each difference is given as `(i, j, self_constraint, other_constraint)`
+    /// with `i < j` (the constraint from `j` to `i` is just the opposite).
+    /// The graphs could have different sizes: the missing constraints are
+    /// then considered as `]-oo,+oo[`.
+    pub fn diff(&self, other: &TimeGraph) -> Vec<(Instant, Instant, TimeSpan, TimeSpan)>
+    {
+        let size = self.size().max(other.size());
+        (0..size)
+            .flat_map(|i| (i+1..size).map(move |j| (i,j)))
+            .filter_map(|(i,j)| {
+                let (a, b) = (self.timespan(i,j), other.timespan(i,j));
+                (a != b).then_some((i, j, a, b))
+            })
+            .collect()
+    }
+
+    /// The longest chain of minimal delays between two instants
+    ///
+    /// Returns the minimal delay from `from` to `to` (i.e. the lower bound of
+    /// their constraint) and a sequence of instants, from `from` to `to`,
+    /// which realizes it: each intermediate instant `k` is strictly after the
+    /// previous one and lies on a tight path (the minimal delay through `k`
+    /// equals the direct one). This is the critical path of PERT/CPM methods.
+    ///
+    /// If there is no minimal delay from `from` to `to` (or if one of them
+    /// is out of the graph), the delay is -&infin; and the path is empty.
+    pub fn critical_path(&self, from: Instant, to: Instant) -> (TimeValue, Vec<Instant>)
+    {
+        if from == to {
+            return (TimeValue::default(), vec![from]);
+        }
+        let delay = self.timespan(from, to).lower_bound();
+        if from >= self.size() || to >= self.size() || !delay.is_finite() {
+            return (-TimeValue::INFINITE, vec![]);
+        }
+        let mut path = vec![from];
+        let mut current = from;
+        while current != to {
+            // SAFETY: all the instants are within the graph
+            let remaining = unsafe { self.lower(current, to) };
+            // the nearest instant on a tight path (the remaining delay strictly decreases)
+            current = (0..self.size())
+                .filter(|&k| k != to)
+                .map(|k| (k, unsafe { self.lower(current, k) }))
+                .filter(|&(k, d)| d.is_strictly_positive() && d.is_finite()
+                    && d + unsafe { self.lower(k, to) } == remaining)
+                .min_by_key(|&(_, d)| d)
+                .map(|(k, _)| k)
+                .unwrap_or(to);
+            path.push(current);
+        }
+        (delay, path)
+    }
+
+    /// Orders all the instants according to their precedences
+    ///
+    /// The instants are sorted so that if `i` is necessarily before `j` (i.e. the
+    /// minimal delay from `i` to `j` is positive and the one from `j` to `i` is not),
+    /// then `i` comes first. The order of the unordered (or simultaneous) instants
+    /// is arbitrary but deterministic.
+    ///
+    /// Since the graph is minimal, the precedences are transitive: an instant
+    /// has strictly more predecessors (or simultaneous instants) than any instant
+    /// before it, so the instants are just sorted by this number, in O(n<sup>2</sup>).
+    pub fn linearize(&self) -> Vec<Instant>
+    {
+        let predecessors = |j: Instant| {
+            // SAFETY: all the instants are within the graph
+            (0..self.size()).filter(|&i| unsafe { self.lower(i, j) }.is_positive()).count()
+        };
+        let mut instants = (0..self.size()).collect::<Vec<_>>();
+        instants.sort_by_cached_key(|&j| predecessors(j));
+        instants
+    }
+
+    /// Checks if all the instants are fixed relatively to a reference one
+    ///
+    /// This is the case if the constraint between `reference` and any other instant
+    /// is reduced to a single value: the graph has then exactly one solution once the
+    /// date of the reference is given (see [`Self::unique_solution`]).
+    ///
+    /// Returns `false` if the reference is out of the graph.
+    #[inline]
+    pub fn is_fully_determined(&self, reference: Instant) -> bool
+    {
+        self.unique_solution(reference).is_some()
+    }
+
+    /// The offsets of all the instants relatively to a reference one, if they are fixed
+    ///
+    /// The offset of the instant `i` (at index `i`) is the only delay allowed from
+    /// `reference` to `i` (so the offset of `reference` itself is zero).
+    /// If an offset is not fixed (see [`Self::is_fully_determined`]) or if the reference
+    /// is out of the graph, `None` is returned.
+    pub fn unique_solution(&self, reference: Instant) -> Option<Vec<TimeValue>>
+    {
+        if reference >= self.size() { return None; }
+        (0..self.size())
+            .map(|i| self.timespan(reference, i))
+            .map(|k| k.is_singleton().then(|| k.lower_bound()))
+            .collect()
+    }
+
     // Checks if two instants are necessarily distinct.
     #[inline]
     pub fn are_distinct_instants(&self, i:Instant, j:Instant) -> bool
@@ -142,6 +314,45 @@ impl TimeGraph {
     }
 }
 
+impl TimeGraph {
+
+    // the number of instants without the last unconstrained ones
+    fn constrained_size(&self) -> Instant
+    {
+        (0..self.size()).rev()
+            .find(|&j| (0..j).any(|i| !self.timespan(i,j).is_all()))
+            .map_or(0, |j| j+1)
+    }
+}
+
+/// Equality of the propagated constraints
+///
+/// Since a propagated graph is minimal, two graphs are equal if they
+/// encode the same constraints, whatever the order of their construction.
+/// The graphs could have different sizes: the missing constraints are
+/// then considered as `]-oo,+oo[`.
+impl PartialEq for TimeGraph {
+    fn eq(&self, other: &Self) -> bool
+    {
+        let size = self.size().max(other.size());
+        (0..size).all(|j| (0..j).all(|i| self.timespan(i,j) == other.timespan(i,j)))
+    }
+}
+
+impl Eq for TimeGraph { }
+
+/// Hashing consistent with the equality
+///
+/// The last unconstrained instants are ignored.
+impl Hash for TimeGraph {
+    fn hash<H: Hasher>(&self, state: &mut H)
+    {
+        let size = self.constrained_size();
+        size.hash(state);
+        (0..size).for_each(|j| (0..j).for_each(|i| self.timespan(i,j).hash(state)));
+    }
+}
+
 impl fmt::Debug for TimeGraph {
 
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
@@ -175,7 +386,6 @@ impl fmt::Display for TimeGraph {
 #[cfg(test)]
 mod tests {
     use crate::graph::*;
-    use crate::graph::propagation::TimePropagation;
 
     #[test]
     pub fn init()
@@ -197,4 +407,210 @@ mod tests {
         graph2.shrink_to_fit();
 
     }
+
+    #[test]
+    pub fn project()
+    {
+        let t = TimeValue::from_ticks;
+        let mut graph = TimeGraph::default();
+        graph.extend(vec![
+            ((0,1), TimeSpan::new(t(5), t(10))),
+            ((1,2), TimeSpan::new(t(5), t(10))),
+            ((2,3), TimeSpan::new(t(1), t(2))),
+        ]).unwrap();
+
+        let sub = graph.project(&[3, 0, 7]);
+        assert_eq!( sub.size(), 3 );
+        assert_eq!( sub.timespan(1,0), graph.timespan(0,3) );
+        assert_eq!( sub.timespan(1,0), TimeSpan::new(t(11), t(22)) );
+        assert_eq!( sub.timespan(0,1), -TimeSpan::new(t(11), t(22)) );
+        assert_eq!( sub.timespan(0,0), TimeSpan::singleton(t(0)) );
+        assert_eq!( sub.timespan(0,2), TimeSpan::all() );
+
+        assert!( graph.project(&[]).is_empty() );
+    }
+
+    #[test]
+    pub fn equality()
+    {
+        use std::hash::{BuildHasher, RandomState};
+        let h = TimeValue::from_hours;
+        let hasher = RandomState::new();
+
+        let mut a = TimeGraph::with_size(3);
+        a.propagate(((0,1), h(1)..=h(2))).unwrap();
+        a.propagate(((1,2), h(1)..=h(3))).unwrap();
+        a.propagate(((0,2), h(3)..=h(4))).unwrap();
+
+        // other order, redundant constraint and larger size
+        let mut b = TimeGraph::with_size(5);
+        b.propagate(((0,2), h(3)..=h(4))).unwrap();
+        b.propagate(((1,2), h(1)..=h(3))).unwrap();
+        b.propagate(((0,2), h(0)..=h(10))).unwrap();
+        b.propagate(((0,1), h(1)..=h(2))).unwrap();
+
+        assert_eq!( a, b );
+        assert_eq!( hasher.hash_one(&a), hasher.hash_one(&b) );
+
+        b.propagate(((3,4), h(1)..=h(2))).unwrap();
+        assert_ne!( a, b );
+        assert_eq!( TimeGraph::with_size(3), TimeGraph::default() );
+        assert_eq!( hasher.hash_one(TimeGraph::with_size(3)), hasher.hash_one(TimeGraph::default()) );
+    }
+
+    #[test]
+    pub fn diff()
+    {
+        let t = TimeValue::from_ticks;
+        let mut a = TimeGraph::default();
+        a.extend(vec![((0,1), TimeSpan::new(t(5), t(10))), ((1,2), TimeSpan::new(t(1), t(2)))]).unwrap();
+        assert!( a.diff(&a).is_empty() );
+
+        let mut b = a.clone();
+        b.propagate(((0,2), TimeSpan::new(t(6), t(7)))).unwrap();
+        assert_eq!( a.diff(&b), vec![
+            (0, 1, TimeSpan::new(t(5), t(10)), TimeSpan::new(t(5), t(6))),
+            (0, 2, TimeSpan::new(t(6), t(12)), TimeSpan::new(t(6), t(7))),
+        ]);
+
+        // missing instants are unconstrained
+        b.propagate(((1,3), TimeSpan::after(t(0)))).unwrap();
+        assert_eq!( a.diff(&b).len(), 5 );
+        assert_eq!( a.diff(&b).last(), Some(&(2, 3, TimeSpan::all(), TimeSpan::after(t(-2)))) );
+        assert_eq!( TimeGraph::with_size(4).diff(&TimeGraph::default()), vec![] );
+    }
+
+    #[test]
+    pub fn critical_path()
+    {
+        let t = TimeValue::from_ticks;
+        let mut graph = TimeGraph::default();
+        graph.extend(vec![
+            ((0,1), TimeSpan::after(t(3))),
+            ((1,3), TimeSpan::after(t(4))),
+            ((0,2), TimeSpan::after(t(2))),
+            ((2,3), TimeSpan::after(t(2))),
+            ((3,4), TimeSpan::after(t(1))),
+        ]).unwrap();
+
+        assert_eq!( graph.critical_path(0, 4), (t(8), vec![0, 1, 3, 4]) );
+        assert_eq!( graph.critical_path(2, 4), (t(3), vec![2, 3, 4]) );
+        assert_eq!( graph.critical_path(0, 1), (t(3), vec![0, 1]) );
+        assert_eq!( graph.critical_path(3, 3), (t(0), vec![3]) );
+
+        // no chain from 1 to 2, nor backward
+        assert_eq!( graph.critical_path(1, 2), (-TimeValue::INFINITE, vec![]) );
+        assert_eq!( graph.critical_path(4, 0), (-TimeValue::INFINITE, vec![]) );
+        assert_eq!( graph.critical_path(0, 9), (-TimeValue::INFINITE, vec![]) );
+    }
+
+    #[test]
+    pub fn contract()
+    {
+        let t = TimeValue::from_secs;
+        let mut graph = TimeGraph::with_size(4);
+        graph.propagate(((0,1), TimeSpan::new(t(1), t(2)))).unwrap();
+        graph.propagate(((1,2), TimeSpan::singleton(t(0)))).unwrap();
+        graph.propagate(((2,3), TimeSpan::new(t(3), t(4)))).unwrap();
+
+        let mut contracted = graph.clone();
+        assert_eq!( Ok(TimePropagation::Propagated), contracted.contract(2, 1));
+        assert_eq!( contracted.size(), 3 );
+        // the last instant takes the index of the dropped one
+        assert_eq!( contracted.timespan(0,2), TimeSpan::new(t(1), t(2)));
+        assert_eq!( contracted.timespan(2,1), TimeSpan::new(t(3), t(4)));
+        assert_eq!( contracted.timespan(0,1), graph.timespan(0,3));
+
+        // dropping the last instant
+        let mut contracted = graph.clone();
+        contracted.propagate(((3,4), TimeSpan::singleton(t(0)))).unwrap();
+        assert_eq!( Ok(TimePropagation::Propagated), contracted.contract(3, 4));
+        assert_eq!( contracted, graph );
+
+        // not simultaneous
+        assert_eq!( Err(TimeInconsistencyError::Recovered), graph.contract(0, 1));
+        assert_eq!( Err(TimeInconsistencyError::Recovered), graph.contract(0, 7));
+        assert_eq!( graph.size(), 4 );
+        assert_eq!( Ok(TimePropagation::Unchanged), graph.contract(3, 3));
+    }
+
+    #[test]
+    pub fn linearize()
+    {
+        let t = TimeValue::from_secs;
+        let mut graph = TimeGraph::with_size(5);
+        graph.propagate(((3,1), TimeSpan::after(t(1)))).unwrap();
+        graph.propagate(((1,4), TimeSpan::after(t(0)))).unwrap();
+        graph.propagate(((4,0), TimeSpan::new(t(2), t(5)))).unwrap();
+        graph.propagate(((2,3), TimeSpan::new(-t(1), t(1)))).unwrap();
+
+        let order = graph.linearize();
+        assert_eq!( order, vec![2, 3, 1, 4, 0] );
+        let position = |i| order.iter().position(|&k| k == i).unwrap();
+        for i in 0..5 {
+            for j in 0..5 {
+                if graph.timespan(i,j).lower_bound().is_positive() && !graph.timespan(j,i).lower_bound().is_positive() {
+                    assert!( position(i) < position(j) );
+                }
+            }
+        }
+
+        // simultaneous instants keep their order
+        let mut graph = TimeGraph::with_size(3);
+        graph.propagate(((2,0), TimeSpan::singleton(t(0)))).unwrap();
+        assert_eq!( graph.linearize(), vec![1, 0, 2] );
+        assert_eq!( TimeGraph::with_size(3).linearize(), vec![0, 1, 2] );
+        assert!( TimeGraph::default().linearize().is_empty() );
+    }
+
+    #[test]
+    pub fn unique_solution()
+    {
+        let t = TimeValue::from_secs;
+        let mut graph = TimeGraph::with_size(3);
+        graph.propagate(((0,1), TimeSpan::singleton(t(2)))).unwrap();
+        graph.propagate(((2,1), TimeSpan::new(t(1), t(3)))).unwrap();
+        assert!( !graph.is_fully_determined(0));
+        assert_eq!( graph.unique_solution(0), None);
+
+        graph.propagate(((2,1), TimeSpan::singleton(t(3)))).unwrap();
+        assert!( graph.is_fully_determined(0));
+        assert_eq!( graph.unique_solution(0), Some(vec![t(0), t(2), -t(1)]));
+        assert_eq!( graph.unique_solution(2), Some(vec![t(1), t(3), t(0)]));
+
+        assert!( !graph.is_fully_determined(3));
+        assert!( TimeGraph::with_size(1).is_fully_determined(0));
+        assert!( !TimeGraph::with_size(2).is_fully_determined(0));
+    }
+
+    #[test]
+    pub fn lifecycle()
+    {
+        let t = TimeValue::from_ticks;
+        assert!( TimeGraph::default().is_empty() );
+        assert!( TimeGraph::with_size(0).is_empty() );
+        assert_eq!( TimeGraph::with_size(0).size(), TimeGraph::default().size() );
+
+        let mut graph = TimeGraph::with_size(3);
+        assert!( !graph.is_empty() );
+        graph.clear();
+        assert!( graph.is_empty() );
+
+        // an inconsistent set of constraints leads to a fatal error
+        let ks = vec![
+            ((0,1), TimeSpan::new(t(5), t(10))),
+            ((1,2), TimeSpan::new(t(5), t(10))),
+            ((0,2), TimeSpan::new(t(0), t(2))),
+        ];
+        assert_eq!( graph.extend(ks), Err(TimeInconsistencyError::Fatal) );
+        assert!( graph.is_empty() );
+
+        // rebuild from scratch
+        graph.clear();
+        graph.extend(vec![((0,1), TimeSpan::new(t(5), t(10)))]).unwrap();
+        assert_eq!( graph.size(), 2 );
+        assert_eq!( graph.timespan(0,1), TimeSpan::new(t(5), t(10)) );
+        graph.resize(3);
+        assert_eq!( graph.timespan(0,2), TimeSpan::all() );
+    }
 }
\ No newline at end of file
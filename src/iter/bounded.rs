@@ -0,0 +1,92 @@
+use core::iter::FusedIterator;
+use crate::*;
+use crate::iter::*;
+
+
+/// # Time window bounding iterator
+///
+/// Each part of the underlying iterator is intersected with the bounds
+/// (see [`TimeConvexIterator::bounded_by`]).
+#[derive(Clone,Debug)]
+pub struct BoundedIter<I:TimeConvexIterator> {
+    bounds: TimeInterval<I::TimePoint>,
+    iter: I
+}
+
+impl<I:TimeConvexIterator> BoundedIter<I> {
+    #[inline]
+    pub(crate) fn new(iter: I, bounds: TimeInterval<I::TimePoint>) -> Self {
+        Self { bounds, iter }
+    }
+}
+
+impl<I:TimeConvexIterator> Iterator for BoundedIter<I>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.bounds.is_empty() {
+            return None;
+        }
+        loop {
+            let tw = self.iter.next()?;
+            if tw.lower > self.bounds.upper {
+                // the parts are sorted so the next ones are also out of bounds
+                self.bounds = TimeInterval::empty();
+                return None;
+            }
+            if tw.upper >= self.bounds.lower {
+                return Some(TimeInterval {
+                    lower: tw.lower.max(self.bounds.lower),
+                    upper: tw.upper.min(self.bounds.upper)
+                });
+            }
+        }
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.bounds.is_empty() { (0, Some(0)) } else { (0, self.iter.size_hint().1) }
+    }
+}
+
+impl<I:TimeConvexIterator> TimeConvexIterator for BoundedIter<I> {
+    type TimePoint = I::TimePoint;
+}
+
+impl<I:TimeConvexIterator+FusedIterator> FusedIterator for BoundedIter<I> { }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::iter::*;
+
+    #[test]
+    fn bounded_by()
+    {
+        let t = TimeValue::from_ticks;
+        let set = TimeSpan::new(t(1), t(5)) | TimeSpan::new(t(10), t(20)) | TimeSpan::after(t(30));
+
+        let bounded = set.iter().bounded_by(TimeSpan::new(t(3), t(15))).collect::<TimeSpans>();
+        assert_eq!( bounded, TimeSpan::new(t(3), t(5)) | TimeSpan::new(t(10), t(15)) );
+        assert_eq!( bounded, &set & &TimeSpans::from(TimeSpan::new(t(3), t(15))) );
+
+        // parts out of bounds are skipped
+        assert_eq!( set.iter().bounded_by(TimeSpan::new(t(6), t(9))).count(), 0 );
+        assert_eq!( set.iter().bounded_by(TimeSpan::after(t(25))).collect::<TimeSpans>(), TimeSpan::after(t(30)) );
+        assert_eq!( set.iter().bounded_by(TimeSpan::all()).collect::<TimeSpans>(), set );
+        assert_eq!( set.iter().bounded_by(TimeSpan::empty()).count(), 0 );
+        assert_eq!( TimeSpans::all().iter().bounded_by(TimeSpan::empty()).count(), 0 );
+
+        // the iteration stops as soon as a part starts after the bounds
+        let mut visited = 0;
+        let bounded = set.iter()
+            .inspect(|_| visited += 1)
+            .bounded_by(TimeSpan::before(t(2)))
+            .collect::<TimeSpans>();
+        assert_eq!( bounded, TimeSpan::new(t(1), t(2)) );
+        assert_eq!( visited, 2 );
+    }
+}
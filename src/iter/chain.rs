@@ -0,0 +1,102 @@
+use core::iter::FusedIterator;
+use crate::*;
+use crate::iter::*;
+
+
+/// # Concatenation of two ordered time window iterators
+///
+/// All the parts of the first iterator are produced, then all the parts
+/// of the second one (see [`TimeConvexIterator::chain_ordered`]).
+#[derive(Clone,Debug)]
+pub struct ChainOrderedIter<I:TimeConvexIterator,J> {
+    first: Option<I>,
+    second: J,
+    // upper bound of the last part of the first iterator, checked in debug mode
+    last: Option<I::TimePoint>
+}
+
+impl<I:TimeConvexIterator,J> ChainOrderedIter<I,J> {
+    #[inline]
+    pub(crate) fn new(first: I, second: J) -> Self {
+        Self { first: Some(first), second, last: None }
+    }
+}
+
+impl<I:TimeConvexIterator,J> Iterator for ChainOrderedIter<I,J>
+    where J: TimeConvexIterator<TimePoint=I::TimePoint>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if let Some(first) = &mut self.first {
+            if let Some(tw) = first.next() {
+                self.last = Some(tw.upper);
+                return Some(tw);
+            }
+            self.first = None;
+        }
+        let tw = self.second.next()?;
+        if let Some(last) = self.last.take() {
+            debug_assert!( last.just_after() < tw.lower,
+                           "the second iterator should start strictly after the end of the first one");
+        }
+        Some(tw)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let (lower, upper) = self.second.size_hint();
+        match &self.first {
+            None => (lower, upper),
+            Some(first) => {
+                let (l, u) = first.size_hint();
+                (l.saturating_add(lower), u.zip(upper).and_then(|(u, upper)| u.checked_add(upper)))
+            }
+        }
+    }
+}
+
+impl<I:TimeConvexIterator,J> TimeConvexIterator for ChainOrderedIter<I,J>
+    where J: TimeConvexIterator<TimePoint=I::TimePoint>
+{
+    type TimePoint = I::TimePoint;
+}
+
+impl<I:TimeConvexIterator,J> FusedIterator for ChainOrderedIter<I,J>
+    where J: TimeConvexIterator<TimePoint=I::TimePoint>+FusedIterator { }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::iter::*;
+
+    #[test]
+    fn chain_ordered()
+    {
+        let t = TimeValue::from_ticks;
+        let morning = TimeSpan::new(t(1), t(5)) | TimeSpan::new(t(10), t(20));
+        let evening = TimeSpan::new(t(30), t(40)) | TimeSpan::after(t(50));
+
+        let day = morning.iter().chain_ordered(evening.iter());
+        assert_eq!( day.size_hint(), (4, Some(4)) );
+        assert_eq!( day.collect::<TimeSpans>(), morning.clone() | evening.clone() );
+
+        assert_eq!( morning.iter().chain_ordered(TimeSpans::empty().iter()).collect::<TimeSpans>(), morning );
+        assert_eq!( TimeSpans::empty().iter().chain_ordered(evening.iter()).collect::<TimeSpans>(), evening );
+    }
+
+    #[test]
+    #[should_panic]
+    #[cfg(debug_assertions)]
+    fn chain_unordered()
+    {
+        let t = TimeValue::from_ticks;
+        let first = TimeSpans::from(TimeSpan::new(t(1), t(5)));
+        let second = TimeSpans::from(TimeSpan::new(t(6), t(9)));
+        // touching intervals should be merged
+        let _ = first.iter().chain_ordered(second.iter()).count();
+    }
+}
@@ -1,4 +1,4 @@
-use std::iter::{Fuse, FusedIterator};
+use core::iter::{Fuse, FusedIterator};
 use crate::*;
 use crate::iter::*;
 
@@ -0,0 +1,95 @@
+use core::iter::FusedIterator;
+use crate::*;
+use crate::iter::*;
+
+
+/// # Merging iterator of touching or overlapping intervals
+///
+/// The intervals of the underlying iterator should be sorted by their lower
+/// bounds but could touch or overlap each other: they are then merged
+/// (see [`TimeConvexIterator::dedup_merge`]).
+#[derive(Clone,Debug)]
+pub struct DedupMergeIter<I:TimeConvexIterator> {
+    pending: Option<TimeInterval<I::TimePoint>>,
+    iter: I
+}
+
+impl<I:TimeConvexIterator> DedupMergeIter<I> {
+    #[inline]
+    pub(crate) fn new(iter: I) -> Self {
+        Self { pending: None, iter }
+    }
+}
+
+impl<I:TimeConvexIterator> Iterator for DedupMergeIter<I>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let mut current = match self.pending.take() {
+            Some(tw) => tw,
+            None => self.iter.find(|tw| !tw.is_empty())?
+        };
+        for tw in self.iter.by_ref() {
+            if tw.is_empty() {
+                continue;
+            }
+            if tw.lower > current.upper.just_after() {
+                self.pending = Some(tw);
+                break;
+            }
+            if tw.upper > current.upper {
+                current.upper = tw.upper;
+            }
+        }
+        Some(current)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the underlying intervals could be empty or merged
+        let pending = self.pending.is_some() as usize;
+        (pending, self.iter.size_hint().1.map(|n| n + pending))
+    }
+}
+
+impl<I:TimeConvexIterator> TimeConvexIterator for DedupMergeIter<I> {
+    type TimePoint = I::TimePoint;
+}
+
+impl<I:TimeConvexIterator+FusedIterator> FusedIterator for DedupMergeIter<I> { }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::iter::*;
+
+    #[test]
+    fn dedup_merge()
+    {
+        let t = TimeValue::from_ticks;
+        let parts = vec![
+            TimeSpan::new(t(1), t(5)),
+            TimeSpan::new(t(3), t(4)),
+            TimeSpan::new(t(6), t(8)),
+            TimeSpan::empty(),
+            TimeSpan::new(t(10), t(20)),
+            TimeSpan::new(t(15), t(25)),
+            TimeSpan::after(t(30)),
+            TimeSpan::after(t(40)),
+        ];
+        let merged = intoiter::IntoConvexIter(parts.into_iter()).dedup_merge().collect::<Vec<_>>();
+        assert_eq!( merged, vec![
+            TimeSpan::new(t(1), t(8)),
+            TimeSpan::new(t(10), t(25)),
+            TimeSpan::after(t(30)),
+        ]);
+
+        // a valid iterator is unchanged
+        let set = TimeSpan::new(t(1), t(5)) | TimeSpan::new(t(10), t(20));
+        assert_eq!( set.iter().dedup_merge().collect::<TimeSpans>(), set );
+        assert_eq!( TimeSpans::empty().iter().dedup_merge().count(), 0 );
+    }
+}
@@ -0,0 +1,90 @@
+use core::iter::FusedIterator;
+use core::ops::Sub;
+use crate::*;
+use crate::iter::*;
+
+
+/// # Iterator over the positions where a window of fixed width fits
+///
+/// Each part of the underlying iterator which is long enough is replaced
+/// by the interval of the start positions of the window
+/// (see [`TimeConvexIterator::fitting_windows`]).
+#[derive(Clone,Debug)]
+pub struct FittingWindowsIter<I:TimeConvexIterator> {
+    width: TimeValue,
+    iter: I
+}
+
+impl<I:TimeConvexIterator> FittingWindowsIter<I> {
+    #[inline]
+    pub(crate) fn new(iter: I, width: TimeValue) -> Self {
+        assert!( width.is_positive(), "the width of the window should be positive" );
+        Self { width, iter }
+    }
+}
+
+impl<I:TimeConvexIterator> Iterator for FittingWindowsIter<I>
+    where
+        I::TimePoint: Sub<Output=TimeValue> + Sub<TimeValue,Output=I::TimePoint>
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        // the infinite bounds lead to an infinite length (and remain unchanged)
+        let width = self.width;
+        self.iter
+            .find(|tw| tw.upper - tw.lower >= width)
+            .map(|tw| TimeInterval { lower: tw.lower, upper: tw.upper - width })
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.iter.size_hint().1)
+    }
+}
+
+impl<I:TimeConvexIterator> TimeConvexIterator for FittingWindowsIter<I>
+    where
+        I::TimePoint: Sub<Output=TimeValue> + Sub<TimeValue,Output=I::TimePoint>
+{
+    type TimePoint = I::TimePoint;
+}
+
+impl<I> FusedIterator for FittingWindowsIter<I>
+    where
+        I: TimeConvexIterator+FusedIterator,
+        I::TimePoint: Sub<Output=TimeValue> + Sub<TimeValue,Output=I::TimePoint>
+{ }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::iter::*;
+    use crate::testing::h;
+
+    #[test]
+    fn fitting_windows()
+    {
+        let free = TimeSlot::new(h(8), h(9)) | TimeSlot::new(h(10), h(13)) | TimeSlot::new(h(15), h(17)) | TimeSlot::after(h(20));
+
+        let starts = free.iter().fitting_windows(TimeValue::from_hours(2)).collect::<TimeSlots>();
+        assert_eq!( starts, TimeSlot::new(h(10), h(11)) | TimeSlot::singleton(h(15)) | TimeSlot::after(h(20)) );
+
+        // each start position gives a window inside the free slots
+        assert!( starts.iter().all(|s| free.covers(&TimeSlot::new(s.lower, s.upper + TimeValue::from_hours(2)).into())) );
+
+        assert_eq!( free.iter().fitting_windows(TimeValue::default()).collect::<TimeSlots>(), free );
+        assert_eq!( TimeSlots::all().iter().fitting_windows(TimeValue::from_hours(2)).collect::<TimeSlots>(), TimeSlots::all() );
+        assert_eq!( TimeSlots::empty().iter().fitting_windows(TimeValue::from_hours(2)).count(), 0 );
+        assert_eq!( free.iter().fitting_windows(TimeValue::from_hours(2)).bounded_by(TimeSlot::before(h(18))).count(), 2 );
+    }
+
+    #[test]
+    #[should_panic]
+    fn fitting_negative_windows()
+    {
+        let _ = TimeSlots::all().iter().fitting_windows(-TimeValue::from_hours(2));
+    }
+}
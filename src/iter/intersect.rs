@@ -1,5 +1,5 @@
-use std::iter::{Fuse, FusedIterator};
-use std::mem::swap;
+use core::iter::{Fuse, FusedIterator};
+use core::mem::swap;
 use crate::*;
 use crate::iter::*;
 
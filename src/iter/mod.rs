@@ -6,6 +6,14 @@ mod union;
 mod transl;
 mod scaling;
 mod excl;
+mod bounded;
+mod dedup;
+mod fitting;
+mod overlap;
+mod stats;
+mod rev;
+mod step;
+mod chain;
 
 use crate::*;
 pub use compl::{TimeComplementary,IterComplementary};
@@ -14,6 +22,14 @@ pub use intersect::{TimeIntersection,IterIntersection};
 pub use transl::TimeTranslation;
 pub use scaling::TimeScaling;
 pub use excl::TimeExclusion;
+pub use bounded::BoundedIter;
+pub use chain::ChainOrderedIter;
+pub use dedup::DedupMergeIter;
+pub use fitting::FittingWindowsIter;
+pub use overlap::{max_overlap, overlap_profile};
+pub use stats::IntervalStats;
+pub use step::step_range;
+pub use rev::{TimeConvexIteratorRev,IterRevComplementary};
 
 /// An iterator over sorted and distinct time intervals
 ///
@@ -30,13 +46,133 @@ pub trait TimeConvexIterator: Iterator<Item=TimeInterval<Self::TimePoint>>+Sized
     /// Typically, the timepoint is [`Timestamp`] when dealing with dates and
     /// [`TimeValue`]  when dealing with durations.
     type TimePoint: TimePoint;
+
+    /// Restricts each time interval to some bounds
+    ///
+    /// The parts which are outside the bounds are skipped and, since the
+    /// intervals are sorted, the iteration stops as soon as a part starts
+    /// after the bounds. So this is more efficient than an intersection
+    /// with a single interval.
+    #[inline]
+    fn bounded_by(self, bounds: TimeInterval<Self::TimePoint>) -> BoundedIter<Self>
+    {
+        BoundedIter::new(self, bounds)
+    }
+
+    /// Gives the uncovered parts within some bounds
+    ///
+    /// This is the complementary restricted to the bounds, computed lazily
+    /// while the intervals are consumed: the gap before the first interval
+    /// (from the lower bound), the gaps between the intervals and the one after
+    /// the last interval (up to the upper bound) are produced, clipped to the bounds.
+    /// For instance, this gives the free time of a busy schedule over a window.
+    #[inline]
+    fn gaps_within(self, bounds: TimeInterval<Self::TimePoint>) -> BoundedIter<IterComplementary<Self>>
+    {
+        self.complementary().bounded_by(bounds)
+    }
+
+    /// Concatenates another iterator which starts strictly after this one
+    ///
+    /// The intervals of this iterator are produced, then the ones of `other`,
+    /// without any merging: this is cheaper than an union but the first interval
+    /// of `other` should start more than one tick after the end of the last interval
+    /// of this iterator (this is checked only in debug mode). This is useful to
+    /// rebuild a time window from disjoint and sorted partitions.
+    #[inline]
+    fn chain_ordered<J>(self, other: J) -> ChainOrderedIter<Self,J>
+        where J: TimeConvexIterator<TimePoint=Self::TimePoint>
+    {
+        ChainOrderedIter::new(self, other)
+    }
+
+    /// Merges the touching or overlapping time intervals
+    ///
+    /// This is a defensive adapter for an iterator which could break the
+    /// contract of disjoint intervals (e.g. an external source): its intervals
+    /// should be sorted by their lower bounds but the ones which touch or
+    /// overlap each other are merged (and the empty ones are skipped), so the
+    /// result could be safely used by the other operators (union, intersection...).
+    #[inline]
+    fn dedup_merge(self) -> DedupMergeIter<Self>
+    {
+        DedupMergeIter::new(self)
+    }
+
+    /// Gives the positions where a window of a given width fits in the time intervals
+    ///
+    /// Each interval `[lower,upper]` which is at least `width` long is replaced
+    /// by the interval of the start positions of the window, i.e. `[lower, upper-width]`.
+    /// The shorter intervals are skipped. This is typically used to find
+    /// where a task of a given duration could be placed.
+    ///
+    /// # Panics
+    /// Panics if the width is negative.
+    #[inline]
+    fn fitting_windows(self, width: TimeValue) -> FittingWindowsIter<Self>
+        where Self::TimePoint: core::ops::Sub<Output=TimeValue> + core::ops::Sub<TimeValue,Output=Self::TimePoint>
+    {
+        FittingWindowsIter::new(self, width)
+    }
+
+    /// Collects the time intervals into an existing time set
+    ///
+    /// The previous content of the set is dropped but its allocated
+    /// memory is reused: in tight loops, this avoids the allocation
+    /// of a new time set for each iteration.
+    ///
+    /// Since the iterator produces sorted and disjoint intervals,
+    /// they are pushed directly without any merge.
+    #[inline]
+    fn collect_into_set(self, set: &mut TimeSet<Self::TimePoint>)
+    {
+        set.0.clear();
+        set.0.extend(self);
+    }
+
+    /// The smallest interval which contains all the time intervals
+    ///
+    /// This is the streaming form of [`TimeWindow::convex_envelope`]: since the
+    /// intervals are sorted, it is defined by the lower bound of the first one and
+    /// the upper bound of the last one. Returns `None` if there is no interval.
+    #[inline]
+    fn envelope(mut self) -> Option<TimeInterval<Self::TimePoint>>
+    {
+        let first = self.next()?;
+        let last = self.last().unwrap_or(first);
+        Some(TimeInterval { lower: first.lower, upper: last.upper })
+    }
+
+    /// Collects at most `n` time intervals and tells if some remain
+    ///
+    /// Unlike `take(n)`, the returned boolean reports the truncation: it is `true`
+    /// if the iterator has more than `n` intervals. The iteration stops just after
+    /// the `n+1`-th interval (which is dropped), so the remaining ones are never computed.
+    #[inline]
+    fn cap_count(mut self, n: usize) -> (Vec<TimeInterval<Self::TimePoint>>, bool)
+    {
+        let parts = self.by_ref().take(n).collect();
+        (parts, self.next().is_some())
+    }
+
+    /// Computes some statistics about the lengths of the time intervals
+    ///
+    /// The number of intervals, their total duration and their minimum and
+    /// maximum lengths are computed in one pass, without any allocation
+    /// (see [`IntervalStats`]).
+    #[inline]
+    fn stats(self) -> IntervalStats
+        where Self::TimePoint: core::ops::Sub<Output=TimeValue>
+    {
+        IntervalStats::from_iter(self)
+    }
 }
 
 
 impl<T:TimePoint> IntoIterator for TimeInterval<T>
 {
     type Item =  Self;
-    type IntoIter = std::option::IntoIter<Self::Item>;
+    type IntoIter = core::option::IntoIter<Self::Item>;
 
     #[inline]
     fn into_iter(self) -> Self::IntoIter
@@ -53,7 +189,7 @@ impl<T:TimePoint> IntoIterator for TimeInterval<T>
 }
 
 
-impl<T:TimePoint> TimeConvexIterator for std::option::IntoIter<TimeInterval<T>> {
+impl<T:TimePoint> TimeConvexIterator for core::option::IntoIter<TimeInterval<T>> {
     type TimePoint = T;
 }
 
@@ -61,7 +197,7 @@ impl<T:TimePoint> TimeConvexIterator for std::option::IntoIter<TimeInterval<T>>
 impl<T:TimePoint> IntoIterator for TimeSet<T>
 {
     type Item = TimeInterval<T>;
-    type IntoIter = intoiter::IntoConvexIter<T,std::vec::IntoIter<Self::Item>>;
+    type IntoIter = intoiter::IntoConvexIter<T,alloc::vec::IntoIter<Self::Item>>;
 
     #[inline] fn into_iter(self) -> Self::IntoIter {
         intoiter::IntoConvexIter(self.0.into_iter())
@@ -72,20 +208,20 @@ impl<T:TimePoint> IntoIterator for TimeSet<T>
 impl<'a,T:TimePoint> IntoIterator for &'a TimeSet<T>
 {
     type Item = TimeInterval<T>;
-    type IntoIter = intoiter::IntoConvexIter<T,std::iter::Copied<std::slice::Iter<'a,TimeInterval<T>>>>;
+    type IntoIter = intoiter::IntoConvexIter<T,core::iter::Copied<core::slice::Iter<'a,TimeInterval<T>>>>;
 
     #[inline] fn into_iter(self) -> Self::IntoIter { intoiter::IntoConvexIter(self.0.iter().copied()) }
 
 }
 
-impl<I> TimeConvexIterator for std::iter::StepBy<I>
+impl<I> TimeConvexIterator for core::iter::StepBy<I>
     where
         I:TimeConvexIterator
 {
     type TimePoint = I::TimePoint;
 }
 
-impl<I,P> TimeConvexIterator for std::iter::Filter<I,P>
+impl<I,P> TimeConvexIterator for core::iter::Filter<I,P>
     where
         I:TimeConvexIterator+Sized,
         P: FnMut(&I::Item)->bool
@@ -93,14 +229,14 @@ impl<I,P> TimeConvexIterator for std::iter::Filter<I,P>
     type TimePoint = I::TimePoint;
 }
 
-impl<I> TimeConvexIterator for std::iter::Peekable<I>
+impl<I> TimeConvexIterator for core::iter::Peekable<I>
     where
         I:TimeConvexIterator
 {
     type TimePoint = I::TimePoint;
 }
 
-impl<I,P> TimeConvexIterator for std::iter::SkipWhile<I,P>
+impl<I,P> TimeConvexIterator for core::iter::SkipWhile<I,P>
     where
         I:TimeConvexIterator+Sized,
         P: FnMut(&I::Item)->bool
@@ -108,7 +244,7 @@ impl<I,P> TimeConvexIterator for std::iter::SkipWhile<I,P>
     type TimePoint = I::TimePoint;
 }
 
-impl<I,P> TimeConvexIterator for std::iter::TakeWhile<I,P>
+impl<I,P> TimeConvexIterator for core::iter::TakeWhile<I,P>
     where
         I:TimeConvexIterator+Sized,
         P: FnMut(&I::Item)->bool
@@ -116,21 +252,21 @@ impl<I,P> TimeConvexIterator for std::iter::TakeWhile<I,P>
     type TimePoint = I::TimePoint;
 }
 
-impl<I> TimeConvexIterator for std::iter::Skip<I>
+impl<I> TimeConvexIterator for core::iter::Skip<I>
     where
         I:TimeConvexIterator
 {
     type TimePoint = I::TimePoint;
 }
 
-impl<I> TimeConvexIterator for std::iter::Take<I>
+impl<I> TimeConvexIterator for core::iter::Take<I>
     where
         I:TimeConvexIterator
 {
     type TimePoint = I::TimePoint;
 }
 
-impl<I,F> TimeConvexIterator for std::iter::Inspect<I,F>
+impl<I,F> TimeConvexIterator for core::iter::Inspect<I,F>
     where
         I:TimeConvexIterator,
         F: FnMut(&I::Item), I: Sized
@@ -139,7 +275,7 @@ impl<I,F> TimeConvexIterator for std::iter::Inspect<I,F>
 }
 
 pub(crate) mod intoiter {
-    use std::iter::FusedIterator;
+    use core::iter::FusedIterator;
     use crate::*;
     use crate::iter::*;
 
@@ -170,4 +306,11 @@ pub(crate) mod intoiter {
         #[inline]
         fn nth(&mut self, n: usize) -> Option<Self::Item> { self.0.nth(n) }
     }
+
+    impl<T: TimePoint, I> DoubleEndedIterator for IntoConvexIter<T, I>
+        where I: DoubleEndedIterator + Iterator<Item=TimeInterval<T>>
+    {
+        #[inline]
+        fn next_back(&mut self) -> Option<Self::Item> { self.0.next_back() }
+    }
 }
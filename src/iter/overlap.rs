@@ -0,0 +1,114 @@
+use crate::*;
+
+
+// the sweep events: +1 at the start of each interval and -1 just after its end
+fn overlap_events<T,I>(iter: I) -> Vec<(T,isize)>
+    where
+        T: TimePoint,
+        I: IntoIterator<Item=TimeInterval<T>>
+{
+    let mut events = iter.into_iter()
+        .filter(|tw| !tw.is_empty())
+        .flat_map(|tw| [(tw.lower, 1), (tw.upper.just_after(), -1)])
+        .collect::<Vec<_>>();
+    events.sort_unstable_by_key(|e| e.0);
+    events
+}
+
+/// The maximum number of intervals which overlap at a same time point
+///
+/// The intervals could be given in any order and could overlap each other
+/// (the empty ones are ignored). Returns 0 if there is no interval.
+pub fn max_overlap<T,I>(iter: I) -> usize
+    where
+        T: TimePoint,
+        I: IntoIterator<Item=TimeInterval<T>>
+{
+    let events = overlap_events(iter);
+    let mut count = 0;
+    let mut max = 0;
+    for (i, &(t, delta)) in events.iter().enumerate() {
+        count += delta;
+        // the maximum is checked only when all the events at `t` are processed
+        if events.get(i+1).map(|e| e.0 != t).unwrap_or(true) {
+            max = max.max(count);
+        }
+    }
+    max as usize
+}
+
+/// The number of overlapping intervals over time
+///
+/// The returned step function is a sorted list of disjoint intervals with the
+/// number of given intervals which contain them. Only the time points covered by
+/// at least one interval are listed and two successive parts always have
+/// different counts if they touch each other.
+///
+/// The intervals could be given in any order and could overlap each other
+/// (the empty ones are ignored).
+pub fn overlap_profile<T,I>(iter: I) -> Vec<(TimeInterval<T>, usize)>
+    where
+        T: TimePoint,
+        I: IntoIterator<Item=TimeInterval<T>>
+{
+    let events = overlap_events(iter);
+    let mut profile: Vec<(TimeInterval<T>, usize)> = Vec::new();
+    let mut count = 0;
+    for (i, &(t, delta)) in events.iter().enumerate() {
+        count += delta;
+        match events.get(i+1) {
+            Some(&(next, _)) if next != t && count > 0 => {
+                let part = TimeInterval { lower: t, upper: next.just_before() };
+                match profile.last_mut() {
+                    Some((last, n)) if *n == count as usize && last.upper.just_after() == t => {
+                        last.upper = part.upper
+                    }
+                    _ => profile.push((part, count as usize))
+                }
+            }
+            _ => { }
+        }
+    }
+    profile
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::iter::*;
+
+    #[test]
+    fn overlaps()
+    {
+        let t = TimeValue::from_ticks;
+        let events = [
+            TimeSpan::new(t(10), t(20)),
+            TimeSpan::new(t(0), t(5)),
+            TimeSpan::new(t(15), t(30)),
+            TimeSpan::new(t(18), t(19)),
+            TimeSpan::empty(),
+            TimeSpan::new(t(6), t(9)),
+        ];
+        assert_eq!( max_overlap(events), 3 );
+        assert_eq!( overlap_profile(events), vec![
+            (TimeSpan::new(t(0), t(14)), 1),
+            (TimeSpan::new(t(15), t(17)), 2),
+            (TimeSpan::new(t(18), t(19)), 3),
+            (TimeSpan::singleton(t(20)), 2),
+            (TimeSpan::new(t(21), t(30)), 1),
+        ]);
+
+        // touching intervals do not overlap
+        assert_eq!( max_overlap([TimeSpan::new(t(0), t(5)), TimeSpan::new(t(6), t(9))]), 1 );
+        assert_eq!( max_overlap([TimeSpan::new(t(0), t(5)), TimeSpan::new(t(5), t(9))]), 2 );
+
+        // unbounded intervals
+        assert_eq!( overlap_profile([TimeSpan::after(t(5)), TimeSpan::all()]), vec![
+            (TimeSpan::before(t(4)), 1),
+            (TimeSpan::after(t(5)), 2),
+        ]);
+        assert_eq!( max_overlap(Vec::<TimeSpan>::new()), 0 );
+        assert!( overlap_profile(Vec::<TimeSpan>::new()).is_empty() );
+    }
+}
@@ -0,0 +1,154 @@
+use core::iter::{Fuse, FusedIterator, Rev};
+use crate::*;
+use crate::iter::*;
+
+/// An iterator over sorted and distinct time intervals, in descending order
+///
+/// This is the reversed counterpart of [`TimeConvexIterator`]: the time intervals
+/// are disjoint (with a gap of at least one tick) but they are produced from the
+/// latest to the earliest one. It is typically obtained by calling `rev()` on a
+/// double-ended time convex iterator (e.g. the iterator of a time set).
+///
+/// Since the union and intersection algorithms expect ascending intervals,
+/// they are not available on this iterator. Only the descending variant of
+/// the complementary is provided, and `rev()` gives back an ascending
+/// [`TimeConvexIterator`].
+pub trait TimeConvexIteratorRev: Iterator<Item=TimeInterval<Self::TimePoint>>+Sized
+{
+    /// The type of the underlying time data.
+    type TimePoint: TimePoint;
+
+    /// Gets the complementary of the time intervals, in descending order
+    ///
+    /// This produces the same intervals as [`TimeComplementary::complementary`]
+    /// but from the latest to the earliest one.
+    #[inline]
+    fn complementary(self) -> IterRevComplementary<Self>
+    {
+        IterRevComplementary::new(self)
+    }
+}
+
+impl<I> TimeConvexIteratorRev for Rev<I>
+    where
+        I: TimeConvexIterator+DoubleEndedIterator
+{
+    type TimePoint = I::TimePoint;
+}
+
+impl<I> TimeConvexIterator for Rev<I>
+    where
+        I: TimeConvexIteratorRev+DoubleEndedIterator
+{
+    type TimePoint = I::TimePoint;
+}
+
+
+/// # The complementary iterator of a time set, in descending order
+///
+/// See [`TimeConvexIteratorRev::complementary`].
+pub struct IterRevComplementary<I:TimeConvexIteratorRev>
+{
+    iter: Fuse<I>,
+    upper: I::TimePoint
+}
+
+impl<I:TimeConvexIteratorRev> IterRevComplementary<I>
+{
+    pub(crate) fn new(iter: I) -> Self {
+        Self {
+            iter: iter.fuse(),
+            upper: I::TimePoint::INFINITE
+        }
+    }
+}
+
+impl<I:TimeConvexIteratorRev> TimeConvexIteratorRev for IterRevComplementary<I> {
+    type TimePoint = I::TimePoint;
+}
+
+impl<I:TimeConvexIteratorRev> FusedIterator for IterRevComplementary<I> { }
+
+
+impl<I:TimeConvexIteratorRev> Iterator for IterRevComplementary<I>
+{
+    type Item = TimeInterval<I::TimePoint>;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        if self.upper.is_future_infinite() {
+            // just starting the iteration
+            let start = self.iter.next()
+                .and_then(|next| {
+                    let lower = next.upper_bound().just_after();
+                    self.upper = next.lower_bound().just_before();
+                    if lower == I::TimePoint::INFINITE {
+                        None
+                    } else {
+                        Some(TimeInterval { lower, upper: I::TimePoint::INFINITE })
+                    }
+                });
+            if start.is_some() { return start; }
+        }
+        for next in self.iter.by_ref()
+        {
+            if next.upper_bound() < self.upper {
+                let result = TimeInterval {
+                    lower: next.upper_bound().just_after(),
+                    upper: self.upper
+                };
+                self.upper = next.lower_bound().just_before();
+                return Some(result);
+            }
+        }
+        if self.upper.is_past_infinite() {
+            None
+        } else {
+            let result = TimeInterval {
+                lower: -I::TimePoint::INFINITE,
+                upper: self.upper
+            };
+            self.upper = -I::TimePoint::INFINITE;
+            Some(result)
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let (min,max) = self.iter.size_hint();
+        if self.upper.is_finite() {
+            (min.saturating_sub(1), max)
+        } else {
+            (min.saturating_sub(1), max.map(|i| i + 1))
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::iter::*;
+
+    #[test]
+    fn reversed_complementary()
+    {
+        let t = TimeValue::from_secs;
+        let sets = [
+            TimeSpans::empty(),
+            TimeSpans::all(),
+            TimeSpan::new(t(1), t(3)) | TimeSpan::new(t(5), t(8)) | TimeSpan::singleton(t(10)),
+            TimeSpan::before(t(1)) | TimeSpan::new(t(5), t(8)),
+            TimeSpan::new(t(1), t(3)) | TimeSpan::after(t(5)),
+            TimeSpan::before(t(1)) | TimeSpan::after(t(5)),
+        ];
+        for set in sets {
+            let mut expected = set.iter().complementary().collect::<Vec<_>>();
+            expected.reverse();
+            assert_eq!( set.iter().rev().complementary().collect::<Vec<_>>(), expected );
+
+            // reversing again gives back an ascending iterator
+            assert_eq!( set.iter().rev().rev().complementary().collect::<TimeSpans>(), !set.clone() );
+        }
+    }
+}
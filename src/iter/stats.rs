@@ -0,0 +1,91 @@
+use core::fmt;
+use crate::*;
+
+
+/// Statistics about the durations of the parts of a time window
+///
+/// They are computed in one pass by [`crate::iter::TimeConvexIterator::stats`].
+/// The minimum and maximum lengths are computed only over the bounded
+/// parts (and are zero if there is none) but the presence of unbounded
+/// parts is flagged (then, the total duration is infinite).
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub struct IntervalStats {
+    /// The number of parts (bounded or not)
+    pub count: usize,
+    /// The sum of the lengths of all the parts
+    pub total: TimeValue,
+    /// The length of the shortest bounded part
+    pub min: TimeValue,
+    /// The length of the longest bounded part
+    pub max: TimeValue,
+    /// Are there unbounded parts ?
+    pub unbounded: bool,
+}
+
+impl IntervalStats {
+
+    pub(crate) fn from_iter<T,I>(iter: I) -> Self
+        where
+            T: TimePoint + core::ops::Sub<Output=TimeValue>,
+            I: Iterator<Item=TimeInterval<T>>
+    {
+        let mut bounded = 0;
+        iter.fold(Self::default(), |mut stats, tw| {
+            stats.count += 1;
+            if tw.lower.is_finite() && tw.upper.is_finite() {
+                let len = tw.upper - tw.lower;
+                stats.total += len;
+                bounded += 1;
+                if bounded == 1 {
+                    stats.min = len;
+                    stats.max = len;
+                } else {
+                    stats.min = stats.min.min(len);
+                    stats.max = stats.max.max(len);
+                }
+            } else {
+                stats.total = TimeValue::INFINITE;
+                stats.unbounded = true;
+            }
+            stats
+        })
+    }
+}
+
+impl fmt::Display for IntervalStats
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result
+    {
+        write!(formatter, "count={} total={} min={} max={}", self.count, self.total, self.min, self.max)?;
+        if self.unbounded {
+            write!(formatter, " (unbounded)")
+        } else {
+            Ok(())
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::iter::{IntervalStats, TimeConvexIterator};
+
+    #[test]
+    fn stats()
+    {
+        let t = TimeValue::from_secs;
+        let a = TimeSet::convex(t(1),t(4)) | TimeSet::convex(t(10),t(20)) | TimeSet::singleton(t(30));
+
+        let stats = a.iter().stats();
+        assert_eq!( stats, IntervalStats { count: 3, total: t(13), min: t(0), max: t(10), unbounded: false });
+        assert_eq!( stats.to_string(), "count=3 total=13s min=0 max=10s");
+
+        let stats = (a | TimeInterval::after(t(50))).iter().stats();
+        assert_eq!( stats, IntervalStats { count: 4, total: TimeValue::INFINITE, min: t(0), max: t(10), unbounded: true });
+        assert_eq!( stats.to_string(), "count=4 total=+oo min=0 max=10s (unbounded)");
+
+        assert_eq!( TimeSpans::empty().iter().stats(), IntervalStats::default());
+        assert!( TimeSpans::all().iter().stats().unbounded );
+    }
+}
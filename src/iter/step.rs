@@ -0,0 +1,54 @@
+use core::ops::Add;
+use crate::*;
+
+
+/// Iterates over the time points from `start` to `end` (excluded) by steps of `by`
+///
+/// The time points are `start`, `start+by`, `start+2*by`... while they are
+/// strictly before `end`: if `by` does not divide the span, the last point
+/// is less than `by` before `end`. There is no point if `end <= start` or
+/// if `start` is infinite.
+///
+/// This is a stable alternative to the range syntax (since `core::iter::Step`
+/// is unstable), for time values as well as for timestamps.
+///
+/// # Panics
+/// Panics if the step is not strictly positive or if it is infinite.
+pub fn step_range<T>(start: T, end: T, by: TimeValue) -> impl Iterator<Item=T>
+    where T: TimePoint + Add<TimeValue,Output=T>
+{
+    assert!( by.is_strictly_positive() && by.is_finite(), "the step should be strictly positive" );
+    core::iter::successors(Some(start).filter(|t| t.is_finite()), move |&t| Some(t + by))
+        .take_while(move |&t| t < end)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::iter::*;
+    use crate::testing::h;
+
+    #[test]
+    fn step_ranges()
+    {
+        let t = TimeValue::from_mins;
+        assert_eq!( step_range(t(0), t(60), t(15)).collect::<Vec<_>>(), vec![t(0), t(15), t(30), t(45)]);
+        assert_eq!( step_range(t(0), t(50), t(15)).collect::<Vec<_>>(), vec![t(0), t(15), t(30), t(45)]);
+        assert_eq!( step_range(-t(10), t(10), t(7)).collect::<Vec<_>>(), vec![-t(10), -t(3), t(4)]);
+        assert_eq!( step_range(t(0), t(0), t(15)).count(), 0);
+        assert_eq!( step_range(t(10), t(0), t(15)).count(), 0);
+        assert_eq!( step_range(-TimeValue::INFINITE, t(0), t(15)).count(), 0);
+        assert_eq!( step_range(TimeValue::from_ticks(i64::MAX - t(20).as_ticks()), TimeValue::INFINITE, t(15)).count(), 2);
+
+        let hours = step_range(h(8), h(12), TimeValue::from_hours(1)).collect::<Vec<_>>();
+        assert_eq!( hours, vec![h(8), h(9), h(10), h(11)]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn step_range_null_step()
+    {
+        let _ = step_range(TimeValue::default(), TimeValue::from_secs(1), TimeValue::default());
+    }
+}
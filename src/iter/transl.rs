@@ -1,4 +1,4 @@
-use std::ops::Add;
+use core::ops::Add;
 use crate::*;
 use crate::iter::*;
 
@@ -52,7 +52,7 @@ impl<I:TimeConvexIterator> TimeTranslation<&TimeSpan> for I
     where
         I::Item: Add<TimeSpan,Output=I::Item>
 {
-    type Output = crate::iter::intoiter::IntoConvexIter<I::TimePoint,std::vec::IntoIter<TimeInterval<I::TimePoint>>>;
+    type Output = crate::iter::intoiter::IntoConvexIter<I::TimePoint,alloc::vec::IntoIter<TimeInterval<I::TimePoint>>>;
 
     fn translation(self, ts: &TimeSpan) -> Self::Output {
         let tw = self.fold(TimeSet::<I::TimePoint>::empty(), |r,tw| r | (tw + *ts));
@@ -1,5 +1,5 @@
-use std::iter::{Fuse, FusedIterator};
-use std::mem::swap;
+use core::iter::{Fuse, FusedIterator};
+use core::mem::swap;
 use crate::*;
 use crate::iter::*;
 
@@ -186,8 +186,8 @@ impl<I,J> Iterator for IterUnion<I,J>
                 UnionState::WaitJ => {
                     match self.j.next() {
                         None => {
-                            /* end of the iterator over i...*/
-                            self.state = UnionState::OnlyJ;
+                            /* end of the iterator over j...*/
+                            self.state = UnionState::OnlyI;
                             return Some(self.tmp);
                         },
                         Some(j) if j.upper < self.tmp.lower => {
@@ -259,3 +259,22 @@ impl<I,J> FusedIterator for IterUnion<I,J>
         J:TimeConvexIterator<TimePoint=I::TimePoint> {}
 
 
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::iter::*;
+
+    #[test]
+    fn union_after_second_end()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(0), t(10)) | TimeSpan::new(t(20), t(30));
+        let b = TimeSpans::from(TimeSpan::new(t(5), t(8)));
+
+        // the remaining parts of the first operand are kept
+        assert_eq!( a.iter().union(b.iter()).collect::<Vec<_>>(), vec![TimeSpan::new(t(0), t(10)), TimeSpan::new(t(20), t(30))] );
+        assert_eq!( b.iter().union(a.iter()).collect::<Vec<_>>(), vec![TimeSpan::new(t(0), t(10)), TimeSpan::new(t(20), t(30))] );
+    }
+}
@@ -20,16 +20,39 @@
 //! ## Time constraint management
 //! The module [`graph`] deals with time constraints graph and mainly provides two structures:
 //! * [`graph::TimeGraph`]: the time constraints graph, a time constraint is defined as an interval
-//!     of duration between two instants, a graph could be considered as a collection of time constraints
+//!   of duration between two instants, a graph could be considered as a collection of time constraints
 //! * [`graph::TimeScheduler`]: the scheduler maintains a set of slots (one for each instant) according to
 //!   its time graph
 //!
 //! Any modification of constraints are automatically propagated (see [`graph::TimeGraph`] for more
 //! informations about the propagation algorithm).
 //!
+//! ## Features
+//! The `std` feature is enabled by default. When disabled, the crate is `no_std` (but
+//! still requires `alloc`): the time values, intervals, sets, iterators and graphs remain
+//! available (as the conversions from/to `core::time::Duration`) but the following APIs are dropped:
+//! * the conversions from/to `chrono` (durations and dates)
+//! * `Timestamp::now`, `Timestamp::now_truncated`, `Timestamp::elapsed`, `Timestamp::to_datetime`
+//!   and the `SystemClock` (but another [`Clock`] could be used)
+//! * the formatting of timestamps with a custom format (see [`TimeSetFormat`])
+//! * the calendar based computations (e.g. `WorkingHours`)
+//!
+//! In this case, timestamps are displayed as a duration since 1970-01-01 00:00:00 UTC.
+#![no_std]
+
+#[cfg(any(feature = "std", test))]
+#[macro_use]
+extern crate std;
+
+#[macro_use]
+extern crate alloc;
+
 mod wins;
 pub use wins::*;
 
+mod error;
+pub use error::*;
+
 pub mod iter;
 
 mod ops;
@@ -41,8 +64,9 @@ pub mod graph;
 mod seq;
 
 
-use std::fmt::Debug;
-use std::ops::Neg;
+use core::fmt::Debug;
+use core::ops::Neg;
+use alloc::{boxed::Box, string::{String, ToString}, vec::Vec};
 
 // Inner value to represent infinite values (negative or positive)
 const INFINITE_TIME_VALUE : i64 = i64::MAX;
@@ -104,4 +128,13 @@ pub trait TimePoint : Debug+Clone+Copy+Eq+Ord+Neg<Output=Self>+Sized {
     /// If a time point is infinite (-&infin; or +&infin;),
     /// the *just before* time point does not change and remains infinite.
     fn just_before(&self) -> Self;
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod testing {
+    use crate::*;
+
+    /// The timestamp `n` hours after the origin (shared by the unit tests)
+    pub(crate) fn h(n: i64) -> Timestamp { Timestamp::from_origin(TimeValue::from_hours(n)) }
+}
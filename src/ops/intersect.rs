@@ -1,4 +1,4 @@
-use std::ops::{BitAnd, BitAndAssign};
+use core::ops::{BitAnd, BitAndAssign};
 use crate::*;
 use crate::iter::TimeIntersection;
 
@@ -68,17 +68,17 @@ impl<T:TimePoint,TW> BitAndAssign<TW> for TimeSet<T>
 
 impl<T:TimePoint> BitAndAssign<Self> for TimeSet<T>
 {
+    #[inline]
     fn bitand_assign(&mut self, tw: Self) {
-        // fixme: suppress cloning
-        *self = self.clone().bitand(tw)
+        *self = (&*self).bitand(&tw)
     }
 }
 
 impl<T:TimePoint> BitAndAssign<&Self> for TimeSet<T>
 {
+    #[inline]
     fn bitand_assign(&mut self, tw: &Self) {
-        // fixme: suppress cloning
-        *self = self.clone().bitand(tw)
+        *self = (&*self).bitand(tw)
     }
 }
 
@@ -88,7 +88,7 @@ impl<T:TimePoint> BitAndAssign<&Self> for TimeSet<T>
 impl<T:TimePoint> BitAnd<Self> for TimeSet<T>
 {
     type Output = Self;
-    #[inline] fn bitand(self, tw: Self) -> Self::Output { (&self).bitand(tw) }
+    #[inline] fn bitand(self, tw: Self) -> Self::Output { (&self).bitand(&tw) }
 }
 
 impl<T:TimePoint> BitAnd<&Self> for TimeSet<T>
@@ -110,18 +110,32 @@ impl<T:TimePoint> BitAnd<TimeSet<T>> for &TimeSet<T>
     type Output = TimeSet<T>;
 
     #[inline]
-    fn bitand(self, tw: TimeSet<T>) -> Self::Output {
-        self.into_iter().intersection(tw.into_iter()).collect()
-    }
+    fn bitand(self, tw: TimeSet<T>) -> Self::Output { self.bitand(&tw) }
 }
 
 impl<T:TimePoint> BitAnd<Self> for &TimeSet<T>
 {
     type Output = TimeSet<T>;
 
-    #[inline]
-    fn bitand(self, tw: &TimeSet<T>) -> Self::Output {
-        self.into_iter().intersection(tw.into_iter()).collect()
+    fn bitand(self, tw: &TimeSet<T>) -> Self::Output
+    {
+        // merge-walk over the two sorted lists of intervals in O(n+m):
+        // at each step, the interval which ends first could not intersect
+        // any further interval of the other list, so we skip it
+        let (a, b) = (&self.0, &tw.0);
+        let mut inners = Vec::with_capacity(a.len().max(b.len()));
+        let (mut i, mut j) = (0, 0);
+        while i < a.len() && j < b.len() {
+            // SAFETY: both indexes are checked by the loop condition
+            let (x, y) = unsafe { (a.get_unchecked(i), b.get_unchecked(j)) };
+            let lower = x.lower.max(y.lower);
+            let upper = x.upper.min(y.upper);
+            if lower <= upper {
+                inners.push(TimeInterval { lower, upper });
+            }
+            if x.upper < y.upper { i += 1; } else { j += 1; }
+        }
+        TimeSet(inners)
     }
 }
 
@@ -1,6 +1,7 @@
 mod union;
 mod intersect;
 mod compl;
+mod xor;
 
 mod scaling;
 mod transl;
@@ -13,9 +14,10 @@ mod tests {
     use crate::*;
 
     fn instants() -> Box<[TimeValue]> {
-        (0..100).map(|t| TimeValue::from_secs(t)).collect()
+        (0..100).map(TimeValue::from_secs).collect()
     }
 
+    #[cfg(feature = "std")]
     fn dates() -> Box<[Timestamp]> {
         instants().iter().map(|t| Timestamp::from_origin(*t)).collect()
     }
@@ -27,8 +29,10 @@ mod tests {
         assert_eq!( "[1s,5s]", (TimeInterval::new(t[1],t[4]) | (t[3]..=t[5])).to_string() );
         assert_eq!( "[1s,3s]U{7s}", (t[7] | (t[1]..=t[3])).to_string() );
 
-        let d = dates();
-        assert_eq!( "[00:01,00:03]U{01:10}".to_string(), (d[70] | (d[1]..=d[3])).format_timeset("%M:%S") );
+        #[cfg(feature = "std")] {
+            let d = dates();
+            assert_eq!( "[00:01,00:03]U{01:10}".to_string(), (d[70] | (d[1]..=d[3])).format_timeset("%M:%S") );
+        }
 
     }
 
@@ -40,7 +44,7 @@ mod tests {
         let b1 = TimeValue::from_ticks(179);
         let b2 = TimeValue::from_ticks(279);
 
-        let mut a = TimeSet::convex(a1,a2);
+        let a = TimeSet::convex(a1,a2);
         let b = TimeSet::convex(b1,b2);
 
         assert_eq!(a|b, TimeInterval::new(a1,b2));
@@ -65,7 +69,172 @@ mod tests {
     #[test]
     pub fn intersection()
     {
+        let t = instants();
+        assert_eq!( "[3s,4s]", (TimeInterval::new(t[1],t[4]) & (t[3]..=t[5])).to_string() );
+
+        let a = TimeSet::convex(t[1],t[4]) | TimeSet::convex(t[10],t[20]);
+        let b = TimeSet::convex(t[3],t[12]) | TimeSet::convex(t[15],t[17]) | TimeSet::convex(t[19],t[30]);
+        assert_eq!( "[3s,4s]U[10s,12s]U[15s,17s]U[19s,20s]", (&a & &b).to_string() );
+        assert_eq!( &a & &b, &b & &a );
+        assert_eq!( &a & &TimeSpans::empty(), TimeSpans::empty() );
+        assert_eq!( &a & &TimeSpans::all(), a );
+
+        let mut c = a.clone();
+        c &= &b;
+        assert_eq!( c, a & b );
+    }
+
+    #[test]
+    pub fn intersection_large()
+    {
+        use crate::iter::TimeIntersection;
+        let t = TimeValue::from_ticks;
+        let a: TimeSpans = (0..2000).map(|i| TimeSpan::new(t(10*i), t(10*i+5))).collect();
+        let b: TimeSpans = (0..2000).map(|i| TimeSpan::new(t(10*i+3), t(10*i+8))).collect();
+
+        let c = &a & &b;
+        assert_eq!( c.convex_count(), 2000 );
+        assert_eq!( c, a.into_iter().intersection(b.into_iter()).collect::<TimeSpans>() );
+    }
+
+    #[test]
+    pub fn symmetric_difference()
+    {
+        let t = TimeValue::from_ticks;
+        assert_eq!( TimeSet::convex(t(1),t(4)) ^ TimeSet::convex(t(3),t(5)), TimeSet::convex(t(1),t(2)) | t(5) );
+
+        let a = TimeSet::convex(t(1),t(4)) | TimeSet::convex(t(10),t(20));
+        let b = TimeSet::convex(t(3),t(12)) | TimeSet::convex(t(15),t(17)) | TimeSet::convex(t(19),t(30));
+        assert_eq!( &a ^ &b, TimeSet::convex(t(1),t(2)) | TimeSet::convex(t(5),t(9))
+            | TimeSet::convex(t(13),t(14)) | t(18) | TimeSet::convex(t(21),t(30)) );
+        assert_eq!( &a ^ &b, &b ^ &a );
+        assert_eq!( &a ^ &b, (&a | &b) & !(&a & &b) );
+        assert_eq!( &a ^ &a, TimeSpans::empty() );
+        assert_eq!( &a ^ &TimeSpans::empty(), a );
+        assert_eq!( &a ^ &TimeSpans::all(), !a.clone() );
+
+        // touching parts are joined
+        let c = TimeSet::convex(t(5),t(9));
+        assert_eq!( &a ^ &c, TimeSet::convex(t(1),t(20)) );
+
+        // unbounded parts
+        let d = TimeSpans::from(TimeInterval::after(t(15)));
+        assert_eq!( &d ^ &TimeSpans::from(TimeInterval::after(t(20))), TimeSet::convex(t(15),t(19)) );
+        assert_eq!( &a ^ &d, TimeSet::convex(t(1),t(4)) | TimeSet::convex(t(10),t(14)) | TimeInterval::after(t(21)) );
+
+        // both unbounded below or both unbounded above
+        let below = |x| TimeSpans::from(TimeInterval::before(t(x)));
+        let above = |x| TimeSpans::from(TimeInterval::after(t(x)));
+        assert_eq!( below(5) ^ below(10), TimeSet::convex(t(6),t(10)) );
+        assert_eq!( below(5) ^ below(5), TimeSpans::empty() );
+        assert_eq!( (below(5) | above(20)) ^ (below(10) | above(30)), TimeSet::convex(t(6),t(10)) | TimeSet::convex(t(20),t(29)) );
+        assert_eq!( above(20) ^ above(20), TimeSpans::empty() );
+        assert_eq!( TimeSpans::all() ^ TimeSpans::all(), TimeSpans::empty() );
+        assert_eq!( TimeSpans::all() ^ below(5), above(6) );
+        for (x,y) in [(below(5), below(10)), (below(5) | above(20), below(10) | a.clone()), (above(20), above(20) | b.clone())] {
+            assert_eq!( &x ^ &y, (&x | &y) & !(&x & &y) );
+        }
+
+        let mut e = a.clone();
+        e ^= &b;
+        assert_eq!( e, a.clone() ^ b.clone() );
+        e ^= b;
+        assert_eq!( e, a );
+    }
+
+    #[test]
+    pub fn collect_into_set()
+    {
+        use crate::iter::{TimeConvexIterator, TimeIntersection};
+        let t = instants();
+        let a = TimeSet::convex(t[1],t[4]) | TimeSet::convex(t[10],t[20]);
+        let b = TimeSet::convex(t[3],t[12]) | TimeSet::convex(t[15],t[17]);
 
+        let mut c = TimeSpans::all();
+        a.iter().intersection(b.iter()).collect_into_set(&mut c);
+        assert_eq!( c, &a & &b );
+
+        a.iter().collect_into_set(&mut c);
+        assert_eq!( c, a );
+    }
+
+    #[test]
+    pub fn float_scaling()
+    {
+        let t = TimeValue::from_ticks;
+        let h = TimeValue::from_hours(1);
+        assert_eq!( h * 1.5, TimeValue::from_mins(90) );
+        assert_eq!( h * -0.25, -TimeValue::from_mins(15) );
+        assert_eq!( h / 2.5, TimeValue::from_mins(24) );
+        // previously, the factor was truncated to an integer
+        assert_ne!( h * 1.5, h * 1 );
+        assert_ne!( h / 2.5, h / 2 );
+        assert_ne!( h * 0.5, TimeValue::default() );
+
+        // the result is rounded to the nearest tick
+        assert_eq!( t(3) * 0.5, t(2) );
+        assert_eq!( t(-3) * 0.5, t(-2) );
+        assert_eq!( t(3) * 1e30, TimeValue::INFINITE );
+        assert_eq!( t(3) / 0., TimeValue::INFINITE );
+        assert_eq!( TimeValue::INFINITE * 0.5, TimeValue::INFINITE );
+        assert_eq!( TimeValue::INFINITE * -0.5, -TimeValue::INFINITE );
+        assert_eq!( TimeValue::INFINITE * 0., TimeValue::default() );
+
+        // scaling down could join the parts of a set
+        // (previously, the parts were only checked when scaling up, which never joins them)
+        let set = TimeSpan::new(t(0), t(10)) | TimeSpan::new(t(12), t(20));
+        assert_eq!( set.clone() * 0.5, TimeSpans::from(TimeSpan::new(t(0), t(10))) );
+        assert_eq!( (set.clone() * 2_f64).convex_count(), 2 );
+        let mut scaled = set.clone();
+        scaled *= 0.5;
+        assert_eq!( scaled, set * 0.5 );
+    }
+
+    #[test]
+    pub fn envelope()
+    {
+        use crate::iter::{TimeConvexIterator, TimeUnion};
+        let t = instants();
+        let a = TimeSet::convex(t[1],t[4]) | TimeSet::convex(t[10],t[20]);
+        let b = TimeSet::convex(t[3],t[12]) | TimeInterval::after(t[25]);
+
+        assert_eq!( a.iter().envelope(), Some(a.convex_envelope()) );
+        assert_eq!( a.iter().union(b.iter()).envelope(), Some(TimeInterval::after(t[1])) );
+        assert_eq!( TimeSpan::singleton(t[3]).iter().envelope(), Some(TimeSpan::singleton(t[3])) );
+        assert_eq!( TimeSpans::empty().iter().envelope(), None );
+    }
+
+    #[test]
+    pub fn cap_count()
+    {
+        use crate::iter::{TimeComplementary, TimeConvexIterator};
+        let t = instants();
+        let a = TimeSet::convex(t[1],t[4]) | TimeSet::convex(t[10],t[20]) | TimeSet::singleton(t[30]);
+
+        assert_eq!( a.iter().cap_count(2), (vec![TimeSpan::new(t[1],t[4]), TimeSpan::new(t[10],t[20])], true) );
+        assert_eq!( a.iter().cap_count(3), (a.iter().collect(), false) );
+        assert_eq!( a.iter().cap_count(10), (a.iter().collect(), false) );
+        assert_eq!( a.iter().cap_count(0), (vec![], true) );
+        assert_eq!( TimeSpans::empty().iter().cap_count(0), (vec![], false) );
+        assert_eq!( a.iter().complementary().cap_count(1), (vec![TimeSpan::before(t[1].just_before())], true) );
+    }
+
+    #[test]
+    pub fn gaps_within()
+    {
+        use crate::iter::TimeConvexIterator;
+        let t = instants();
+        let busy = TimeSet::convex(t[10],t[20]) | TimeSet::convex(t[30],t[40]);
+
+        let window = TimeSpan::new(t[0], t[50]);
+        assert_eq!( busy.iter().gaps_within(window).collect::<TimeSpans>(), &!busy.clone() & window );
+        assert_eq!( busy.iter().gaps_within(window).count(), 3 );
+        assert_eq!( busy.iter().gaps_within(TimeSpan::new(t[15], t[35])).collect::<Vec<_>>(),
+                    vec![TimeSpan::new(t[20].just_after(), t[30].just_before())] );
+        assert_eq!( busy.iter().gaps_within(TimeSpan::new(t[12], t[18])).count(), 0 );
+        assert_eq!( busy.iter().gaps_within(TimeSpan::empty()).count(), 0 );
+        assert_eq!( TimeSpans::empty().iter().gaps_within(window).collect::<Vec<_>>(), vec![window] );
+        assert_eq!( busy.iter().gaps_within(TimeSpan::all()).collect::<TimeSpans>(), !busy );
     }
 
     #[test]
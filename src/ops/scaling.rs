@@ -1,5 +1,5 @@
 use crate::*;
-use std::ops::{Div, DivAssign, Mul, MulAssign};
+use core::ops::{Div, DivAssign, Mul, MulAssign};
 
 //-------------- TIMEVALUE SCALING -----------------------------
 
@@ -59,8 +59,43 @@ macro_rules! timevalscalingbig {
 
 timevalscalingbig!(i128);
 timevalscalingbig!(isize);
-timevalscalingbig!(f32);
-timevalscalingbig!(f64);
+
+// GROUP 2bis: scale factor is a float, the result is rounded to the nearest tick
+
+macro_rules! timevalscalingfloat {
+    ($scale: ty) => {
+        impl Mul<$scale> for TimeValue {
+            type Output = Self;
+            #[inline] fn mul(self, f: $scale) -> Self::Output {
+                scale_float(self, f as f64, false)
+            }
+        }
+        impl Div<$scale> for TimeValue {
+            type Output = Self;
+            #[inline] fn div(self, f: $scale) -> Self::Output {
+                scale_float(self, f as f64, true)
+            }
+        }
+    };
+}
+
+timevalscalingfloat!(f32);
+timevalscalingfloat!(f64);
+
+fn scale_float(t: TimeValue, f: f64, div: bool) -> TimeValue
+{
+    assert!( !f.is_nan(), "can’t scale a time value by NaN");
+    if t.is_zero() {
+        t
+    } else if !t.is_finite() {
+        // an infinite value multiplied by zero is zero by convention
+        if f < 0. { -t } else if f > 0. || div { t } else { TimeValue::default() }
+    } else {
+        let ticks = if div { t.0 as f64 / f } else { t.0 as f64 * f };
+        // the scaling by a power of 2 is exact
+        TimeValue::from_secs_f64(ticks / (1_i64 << SUBSEC_BITLEN) as f64)
+    }
+}
 
 // GROUP 3: scale factor is unsigned and could be greater than i64::MAX (abs value)
 
@@ -305,7 +340,7 @@ macro_rules! timespansscalefloat {
                     .map(|tw| *tw * f)
                     .collect::<Vec<_>>();
                 if f < 0. { inners.reverse() }
-                if f.abs() > 1. { check_joined_inners(&mut inners); }
+                if f.abs() < 1. { check_joined_inners(&mut inners); }
                 Self(inners)
             }
         }
@@ -0,0 +1,78 @@
+use core::ops::{BitXor, BitXorAssign};
+use crate::*;
+
+
+impl<T:TimePoint> BitXorAssign<Self> for TimeSet<T>
+{
+    #[inline]
+    fn bitxor_assign(&mut self, tw: Self) {
+        *self = (&*self).bitxor(&tw)
+    }
+}
+
+impl<T:TimePoint> BitXorAssign<&Self> for TimeSet<T>
+{
+    #[inline]
+    fn bitxor_assign(&mut self, tw: &Self) {
+        *self = (&*self).bitxor(tw)
+    }
+}
+
+impl<T:TimePoint> BitXor<Self> for TimeSet<T>
+{
+    type Output = Self;
+    #[inline] fn bitxor(self, tw: Self) -> Self::Output { (&self).bitxor(&tw) }
+}
+
+impl<T:TimePoint> BitXor<&Self> for TimeSet<T>
+{
+    type Output = Self;
+    #[inline] fn bitxor(self, tw: &Self) -> Self::Output { (&self).bitxor(tw) }
+}
+
+impl<T:TimePoint> BitXor<TimeSet<T>> for &TimeSet<T>
+{
+    type Output = TimeSet<T>;
+    #[inline] fn bitxor(self, tw: TimeSet<T>) -> Self::Output { self.bitxor(&tw) }
+}
+
+/// Symmetric difference of two time sets
+///
+/// The result contains the time points which are in exactly one of the sets.
+impl<T:TimePoint> BitXor<Self> for &TimeSet<T>
+{
+    type Output = TimeSet<T>;
+
+    fn bitxor(self, tw: &TimeSet<T>) -> Self::Output
+    {
+        // merge-walk over the bounds of the two sorted lists of intervals:
+        // an event `(t,false)` enters an interval at `t` and an event `(t,true)`
+        // leaves it just after `t` (so, it is sorted after the entering at `t`)
+        let events = |set: &TimeSet<T>| set.0.iter()
+            .flat_map(|tw| [(tw.lower, false), (tw.upper, true)])
+            .collect::<Vec<_>>();
+        let (a, b) = (events(self), events(tw));
+
+        let mut parts: Vec<TimeInterval<T>> = Vec::with_capacity(self.0.len() + tw.0.len());
+        let (mut i, mut j) = (0, 0);
+        let (mut in_a, mut in_b) = (false, false);
+        let mut start = -T::INFINITE;
+        while i < a.len() || j < b.len() {
+            let from_a = j >= b.len() || (i < a.len() && a[i] <= b[j]);
+            let (t, leaving) = if from_a { i += 1; a[i-1] } else { j += 1; b[j-1] };
+            // (an entering at `start` closes nothing, even if `start` is -oo)
+            if in_a != in_b && (leaving || start < t) {
+                let upper = if leaving { t } else { t.just_before() };
+                if start <= upper && !start.is_future_infinite() {
+                    match parts.last_mut() {
+                        Some(last) if last.upper.just_after() == start => last.upper = upper,
+                        _ => parts.push(TimeInterval { lower: start, upper })
+                    }
+                }
+            }
+            if from_a { in_a = !leaving; } else { in_b = !leaving; }
+            start = if leaving { t.just_after() } else { t };
+        }
+        TimeSet(parts)
+    }
+}
@@ -31,8 +31,6 @@ impl<T:TimePoint,TW> TimeContaining<TW> for TimeSet<T>
 
 impl<T:TimePoint> TimeContaining<Self> for TimeSet<T>
 {
-    fn contains(&self, rhs: &Self) -> bool {
-        // todo: optimise it by using order of inner intervals
-        rhs.into_iter().all(|tw| self.contains(&tw))
-    }
+    #[inline]
+    fn contains(&self, rhs: &Self) -> bool { self.covers(rhs) }
 }
\ No newline at end of file
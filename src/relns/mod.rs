@@ -8,7 +8,7 @@ pub use contain::*;
 
 #[cfg(test)]
 mod tests {
-    use std::fmt::Debug;
+    use core::fmt::Debug;
     use crate::*;
 
 
@@ -31,6 +31,7 @@ mod tests {
         assert!( tw.contains(&TimeValue::from_ticks(-15)));
         assert!(!tw.contains(&TimeValue::from_ticks(10)));
 
+        #[cfg(feature = "std")]
         println!("{}", Timestamp::now().format_timepoint("%F %C"));
     }
 
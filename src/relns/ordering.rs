@@ -1,7 +1,11 @@
-use std::cmp::Ordering;
+use core::cmp::Ordering;
 use crate::{TimeBounds, TimeInterval, TimePoint, TimeSet, TimeWindow};
 
 
+/// Equality with any time window (interval or set)
+///
+/// An interval is equal to a time set if the set has exactly one convex
+/// part equal to it or if both are empty.
 impl<T:TimePoint,TW> PartialEq<TW> for TimeInterval<T>
     where
         TW: TimeWindow<TimePoint=T>
@@ -14,6 +18,11 @@ impl<T:TimePoint,TW> PartialEq<TW> for TimeInterval<T>
     }
 }
 
+/// Equality with any time window (interval or set)
+///
+/// Two time windows are equal if they have the same convex parts,
+/// so a time set is equal to an interval if it has exactly one convex part
+/// equal to it or if both are empty.
 impl<T:TimePoint,TW> PartialEq<TW> for TimeSet<T>
     where
         TW: TimeWindow<TimePoint=T>
@@ -69,3 +78,37 @@ macro_rules! timepartialcmp {
 
 timepartialcmp!(TimeInterval);
 timepartialcmp!(TimeSet);
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn interval_set_equality()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(0), t(10));
+        let b = TimeSpan::new(t(5), t(20));
+        let c = TimeSpan::new(t(30), t(40));
+
+        let ab = TimeSpans::from(a) | b;
+        assert_eq!( ab, TimeSpan::new(t(0), t(20)));
+        assert_eq!( TimeSpan::new(t(0), t(20)), ab);
+        assert_eq!( format!("{}", ab), format!("{}", TimeSpan::new(t(0), t(20))));
+
+        let ac = TimeSpans::from(a) | c;
+        assert_ne!( ac, a);
+        assert_ne!( a, ac);
+        assert_ne!( ac, ac.convex_envelope());
+        assert_ne!( ac.convex_envelope(), ac);
+
+        // all the empty sets are equal
+        let empty = TimeSpans::from(a) & c;
+        assert_eq!( empty, TimeSpan::empty());
+        assert_eq!( TimeSpan::empty(), empty);
+        assert_eq!( format!("{}", empty), format!("{}", TimeSpan::empty()));
+        assert_ne!( empty, a);
+        assert_ne!( a, empty);
+    }
+}
@@ -42,8 +42,8 @@ impl<T:TimePoint, TW> TimeOverlapping<TW> for TimeSet<T>
 
 impl<T:TimePoint> TimeOverlapping<Self> for TimeSet<T>
 {
+    #[inline]
     fn overlaps(&self, rhs: &Self) -> bool {
-        // todo: optimise it by using order of inner intervals
-        rhs.into_iter().any(|tw| self.overlaps(&tw))
+        !self.is_disjoint(rhs)
     }
 }
\ No newline at end of file
@@ -1,4 +1,4 @@
-use std::iter::FusedIterator;
+use core::iter::FusedIterator;
 use crate::{TimePoint, Timestamp, Timestamped, TimeValue};
 use crate::seq::{TimeSeqBackward, TimeSeqForward, TimeSequence};
 
@@ -0,0 +1,135 @@
+use chrono::{Datelike, WeekdaySet};
+use crate::*;
+
+
+/// # A weekly pattern of working hours
+///
+/// The working hours are defined by a set of weekdays and by a time window
+/// within each of these days (as durations from midnight UTC). If the end
+/// is before the start, the window is overnight and ends the next day.
+///
+/// For instance, 9:00–17:00 on weekdays is defined by:
+/// ```
+/// # use chrono::{Weekday, WeekdaySet};
+/// # use chronologic::*;
+/// let weekdays = WeekdaySet::from_array([Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]);
+/// let office = WorkingHours::new(weekdays, TimeValue::from_hours(9), TimeValue::from_hours(17));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WorkingHours {
+    /// The working days
+    pub days: WeekdaySet,
+    /// The start of the working hours (from midnight)
+    pub start: TimeValue,
+    /// The end of the working hours (from midnight)
+    pub end: TimeValue,
+}
+
+impl WorkingHours {
+
+    #[inline]
+    pub fn new(days: WeekdaySet, start: TimeValue, end: TimeValue) -> Self {
+        Self { days, start, end }
+    }
+
+    /// Generates the working slots within a time range
+    ///
+    /// There is one slot for each working day and it is clipped to the range.
+    /// An overnight slot belongs to the day it starts.
+    ///
+    /// # Panics
+    /// Panics if the range is not empty and not bounded.
+    pub fn generate(&self, range: TimeSlot) -> TimeSlots
+    {
+        if range.is_empty() { return TimeSlots::empty(); }
+        assert!( range.is_bounded(), "working hours can’t be generated within an unbounded range");
+
+        let day = TimeValue::from_days(1);
+        let end = if self.end < self.start { self.end + day } else { self.end };
+        // starting the day before to catch an overnight slot
+        let first = range.lower.floor(day) - day;
+        (0..)
+            .map(|n| first + day * (n as i64))
+            .take_while(|&midnight| midnight <= range.upper)
+            .filter(|midnight| self.days.contains(midnight.to_datetime().weekday()))
+            .map(|midnight| {
+                let mut slot = TimeSlot::new(midnight + self.start, midnight + end);
+                slot.truncate_before(range.lower);
+                slot.truncate_after(range.upper);
+                slot
+            })
+            .collect()
+    }
+}
+
+impl TimeSlot {
+
+    /// The duration of this slot within some working hours
+    ///
+    /// This is the total duration of the working slots generated within
+    /// this slot (see [`WorkingHours::generate`]), so how much of the slot
+    /// is actually spent during the working hours.
+    ///
+    /// # Panics
+    /// Panics if the slot is not empty and not bounded.
+    pub fn business_overlap(&self, hours: &WorkingHours) -> TimeValue
+    {
+        hours.generate(*self).iter().map(|slot| slot.duration()).sum()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use chrono::{NaiveDate, Weekday, WeekdaySet};
+    use crate::*;
+
+    fn at(day: u32, hour: i64) -> Timestamp {
+        // 2024-01-01 is a monday
+        Timestamp::from(NaiveDate::from_ymd_opt(2024, 1, day).unwrap().and_hms_opt(0,0,0).unwrap())
+            + TimeValue::from_hours(hour)
+    }
+
+    #[test]
+    fn working_hours()
+    {
+        let weekdays = WeekdaySet::from_array([Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]);
+        let office = WorkingHours::new(weekdays, TimeValue::from_hours(9), TimeValue::from_hours(17));
+
+        // from monday 10:00 to next monday 12:00
+        let slots = office.generate(TimeSlot::new(at(1, 10), at(8, 12)));
+        assert_eq!( slots.convex_count(), 6 );
+        assert_eq!( slots,
+            TimeSlot::new(at(1, 10), at(1, 17))
+                | TimeSlot::new(at(2, 9), at(2, 17))
+                | TimeSlot::new(at(3, 9), at(3, 17))
+                | TimeSlot::new(at(4, 9), at(4, 17))
+                | TimeSlot::new(at(5, 9), at(5, 17))
+                | TimeSlot::new(at(8, 9), at(8, 12))
+        );
+
+        // overnight slots on saturdays
+        let night = WorkingHours::new(WeekdaySet::single(Weekday::Sat), TimeValue::from_hours(22), TimeValue::from_hours(6));
+        assert_eq!( night.generate(TimeSlot::new(at(7, 2), at(14, 4))),
+            TimeSlot::new(at(7, 2), at(7, 6)) | TimeSlot::new(at(13, 22), at(14, 4)) );
+        assert_eq!( night.generate(TimeSlot::new(at(6, 23), at(7, 2))),
+            TimeSlot::new(at(6, 23), at(7, 2)) );
+
+        assert!( office.generate(TimeSlot::new(at(6, 0), at(7, 23))).is_empty() );
+        assert!( office.generate(TimeSlot::empty()).is_empty() );
+    }
+
+    #[test]
+    fn business_overlap()
+    {
+        let weekdays = WeekdaySet::from_array([Weekday::Mon, Weekday::Tue, Weekday::Wed, Weekday::Thu, Weekday::Fri]);
+        let office = WorkingHours::new(weekdays, TimeValue::from_hours(9), TimeValue::from_hours(17));
+
+        // from friday 15:00 to monday 11:00
+        assert_eq!( TimeSlot::new(at(5, 15), at(8, 11)).business_overlap(&office), TimeValue::from_hours(4) );
+        // from monday 10:00 to next monday 12:00
+        assert_eq!( TimeSlot::new(at(1, 10), at(8, 12)).business_overlap(&office), TimeValue::from_hours(7 + 4*8 + 3) );
+        assert_eq!( TimeSlot::new(at(6, 0), at(7, 23)).business_overlap(&office), TimeValue::default() );
+        assert_eq!( TimeSlot::empty().business_overlap(&office), TimeValue::default() );
+    }
+}
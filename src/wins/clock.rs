@@ -0,0 +1,84 @@
+use crate::*;
+
+/// # A source of the current time
+///
+/// Reading the current time through a clock (instead of calling directly
+/// [`Timestamp::now`]) allows to inject a fixed or simulated time,
+/// typically for testing purpose.
+pub trait Clock {
+
+    /// The current timestamp
+    fn now(&self) -> Timestamp;
+
+    /// The current timestamp, floored to a period
+    ///
+    /// For instance, `now_truncated(TimeValue::from_mins(1))` returns the
+    /// beginning of the current minute.
+    #[inline]
+    fn now_truncated(&self, precision: TimeValue) -> Timestamp {
+        self.now().floor(precision)
+    }
+}
+
+/// # The system clock
+///
+/// This is the wall clock, as read by [`Timestamp::now`].
+#[cfg(feature = "std")]
+#[derive(Copy, Clone, Debug, Default)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+    #[inline] fn now(&self) -> Timestamp { Timestamp::now() }
+}
+
+/// # A clock with a manually controlled time
+///
+/// The time does not run by itself: it only changes when it is explicitly
+/// set or advanced.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FixedClock(Timestamp);
+
+impl FixedClock {
+
+    #[inline]
+    pub fn new(t: Timestamp) -> Self { Self(t) }
+
+    /// Sets the current time
+    #[inline]
+    pub fn set(&mut self, t: Timestamp) { self.0 = t; }
+
+    /// Moves the current time forward (or backward if `dt` is negative)
+    #[inline]
+    pub fn advance(&mut self, dt: TimeValue) { self.0 += dt; }
+}
+
+impl Clock for FixedClock {
+    #[inline] fn now(&self) -> Timestamp { self.0 }
+}
+
+impl<C:Clock> Clock for &C {
+    #[inline] fn now(&self) -> Timestamp { C::now(self) }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn fixed_clock()
+    {
+        let start = Timestamp::from_origin(TimeValue::from_hours(10) + TimeValue::from_secs(42));
+        let mut clock = FixedClock::new(start);
+        assert_eq!( clock.now(), start);
+        assert_eq!( start.elapsed_with(&clock), TimeValue::default());
+        assert_eq!( clock.now_truncated(TimeValue::from_hours(1)), Timestamp::from_origin(TimeValue::from_hours(10)));
+
+        clock.advance(TimeValue::from_mins(5));
+        assert_eq!( start.elapsed_with(&clock), TimeValue::from_mins(5));
+
+        clock.set(Timestamp::EPOCH);
+        assert_eq!( start.elapsed_with(&clock), -(TimeValue::from_hours(10) + TimeValue::from_secs(42)));
+    }
+}
@@ -1,5 +1,6 @@
-use std::fmt;
+use core::fmt;
 use crate::*;
+#[cfg(feature = "std")]
 use chrono::format::*;
 
 pub trait TimeSetFormat {
@@ -12,15 +13,21 @@ pub trait TimePointFormat {
 
 impl TimePointFormat for TimeValue {
 
-    fn format_timepoint(self, _timefmt: &str) -> String {
+    /// Formats the duration with its units
+    ///
+    /// If the format contains `%t`, the residual ticks are also displayed
+    /// (see the `t` unit of durations) in order to get the exact value.
+    fn format_timepoint(self, timefmt: &str) -> String {
+        let ticks = timefmt.contains("%t");
         if self.is_positive() {
-            format_duration(self.as_ticks())
+            format_duration(self.as_ticks(), false, ticks)
         } else {
-            format!("- {}", &format_duration(-self.as_ticks()))
+            format!("- {}", &format_duration(-self.as_ticks(), false, ticks))
         }
     }
 }
 
+#[cfg(feature = "std")]
 impl TimePointFormat for Timestamp {
 
     fn format_timepoint(self, timefmt: &str) -> String {
@@ -28,56 +35,132 @@ impl TimePointFormat for Timestamp {
     }
 }
 
-fn format_duration(t: i64) -> String
+/// Formats a positive duration with its units
+///
+/// The compact form has no space between the units
+/// and uses `m` for the minutes (e.g. `2d3h15m` instead of `2d 3h 15min`).
+///
+/// If `ticks` is set, the residual ticks which are not represented
+/// by the nanoseconds are appended (e.g. `1s 500ms 1t`) so the decomposition
+/// is exact (i.e. parsing it gives back the same time value).
+fn format_duration(t: i64, compact: bool, ticks: bool) -> String
 {
     assert! ( t >= 0 );
-    let mut nanos = TimeValue::from_ticks(t).subsec_nanos();
-    let _remaining_ticks = t - TimeValue::from_nanos(nanos as i64).as_ticks();
+    let sep = if compact { "" } else { " " };
 
-    fn concat_unit<F:Fn(i64)->TimeValue>(ticks: (i64,String), convert:F, unit: &str) -> (i64, String)
+    let concat_unit = |ticks: (i64,String), convert: fn(i64)->TimeValue, unit: &str| -> (i64, String)
     {
         let x = ticks.0 / (convert)(1).as_ticks();
         if x == 0 {
             ticks
          } else {
-            (ticks.0 - (convert)(x).as_ticks(), format!("{}{}{} ", &ticks.1, x, unit))
+            (ticks.0 - (convert)(x).as_ticks(), format!("{}{}{}{}", &ticks.1, x, unit, sep))
         }
-    }
+    };
     if t == 0 { return "0".to_string(); }
-    let ticks = (t, String::new());
-    let ticks = concat_unit(ticks, TimeValue::from_years, "y");
-    let ticks = concat_unit(ticks, TimeValue::from_months, "mo");
-    let ticks = concat_unit(ticks, TimeValue::from_days, "d");
-    let ticks = concat_unit(ticks, TimeValue::from_hours, "h");
-    let ticks = concat_unit(ticks, TimeValue::from_mins, "min");
-    let ticks = concat_unit(ticks, TimeValue::from_secs, "s");
-
-    let mut str = ticks.1;
-    if nanos > 1_000_000 {
-        str = format!("{}{}ms ", str, nanos/1_000_000);
-        nanos %= 1_000_000;
+    let ticks_str = (t, String::new());
+    let ticks_str = concat_unit(ticks_str, TimeValue::from_years, "y");
+    let ticks_str = concat_unit(ticks_str, TimeValue::from_months, "mo");
+    let ticks_str = concat_unit(ticks_str, TimeValue::from_days, "d");
+    let ticks_str = concat_unit(ticks_str, TimeValue::from_hours, "h");
+    let ticks_str = concat_unit(ticks_str, TimeValue::from_mins, if compact { "m" } else { "min" });
+    let (subsec, mut str) = concat_unit(ticks_str, TimeValue::from_secs, "s");
+
+    // the nanoseconds are split in ms, us and ns
+    let split = |nanos: i32| {
+        let ms = if nanos > 1_000_000 { nanos/1_000_000 } else { 0 };
+        let us = if nanos - ms*1_000_000 > 1_000 { (nanos - ms*1_000_000)/1_000 } else { 0 };
+        (ms, us, nanos - ms*1_000_000 - us*1_000)
+    };
+    let mut nanos = TimeValue::from_ticks(subsec).subsec_nanos();
+    let mut remaining_ticks = 0;
+    if ticks {
+        // the nanoseconds are rounded so they could exceed the subsecond ticks
+        let as_ticks = |(ms,us,ns): (i32,i32,i32)|
+            (TimeValue::from_millis(ms as i64) + TimeValue::from_micros(us as i64) + TimeValue::from_nanos(ns as i64)).as_ticks();
+        while nanos > 0 && as_ticks(split(nanos)) > subsec { nanos -= 1; }
+        remaining_ticks = subsec - as_ticks(split(nanos));
+    }
+    let (ms, us, ns) = split(nanos);
+    if ms > 0 {
+        str = format!("{}{}ms{}", str, ms, sep);
     }
-    if nanos > 1_000 {
-        str = format!("{}{}us ", str, nanos/1_000);
-        nanos %= 1_000;
+    if us > 0 {
+        str = format!("{}{}us{}", str, us, sep);
     }
-    if nanos > 0 {
-        str = format!("{}{}ns ", str, nanos);
+    if ns > 0 {
+        str = format!("{}{}ns{}", str, ns, sep);
     }
-    /*
-    todo: displaying type with format %t
     if remaining_ticks != 0 {
-        str = format!("{}{}t ", str, remaining_ticks);
+        str = format!("{}{}t{}", str, remaining_ticks, sep);
     }
-    */
 
-    if str.pop().is_none() {
+    if !compact { str.pop(); }
+    if str.is_empty() {
         "0".to_string()
     } else {
         str
     }
 }
 
+impl TimeValue {
+
+    /// Formats the duration as a coarse human phrase
+    ///
+    /// Only the largest relevant unit is kept (days, hours or minutes) and
+    /// the value is rounded to the nearest one (e.g. `2 hours` for 1h 40min).
+    /// A duration below half a minute is displayed as `just now` and an
+    /// infinite one as `forever`. The sign is ignored (see
+    /// [`Timestamp::format_relative_to`] to get `ago` or `from now`).
+    ///
+    /// This is intended for user interfaces: use the [`fmt::Display`]
+    /// implementation to get the exact value.
+    pub fn format_relative(&self) -> String
+    {
+        if !self.is_finite() { return "forever".to_string(); }
+        let t = self.as_ticks().abs();
+        let round = |unit: TimeValue| {
+            let unit = unit.as_ticks();
+            t / unit + i64::from(t % unit >= unit / 2)
+        };
+        let (n, unit) = match round(TimeValue::from_mins(1)) {
+            0 => return "just now".to_string(),
+            n if n < 60 => (n, "minute"),
+            _ => match round(TimeValue::from_hours(1)) {
+                n if n < 24 => (n, "hour"),
+                _ => (round(TimeValue::from_days(1)), "day")
+            }
+        };
+        if n == 1 { format!("1 {}", unit) } else { format!("{} {}s", n, unit) }
+    }
+}
+
+impl Timestamp {
+
+    /// Formats this timestamp as a coarse human phrase relatively to a reference
+    ///
+    /// The distance to the reference is formatted as for [`TimeValue::format_relative`]
+    /// and followed by `ago` if this timestamp is before the reference or `from now`
+    /// otherwise (e.g. `3 days ago`). The future infinite is displayed as
+    /// `never` and the past infinite as `forever`.
+    pub fn format_relative_to(&self, reference: Timestamp) -> String
+    {
+        if self.is_future_infinite() {
+            "never".to_string()
+        } else if self.is_past_infinite() {
+            "forever".to_string()
+        } else {
+            let delay = *self - reference;
+            match delay.format_relative() {
+                rel if rel == "just now" => rel,
+                rel if delay.is_strictly_negative() => format!("{} ago", rel),
+                rel => format!("{} from now", rel)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
 fn format_timestamp(t: Timestamp, timefmt: &str) -> DelayedFormat<StrftimeItems<'_>> {
     t.to_datetime().format(timefmt)
 }
@@ -85,22 +168,22 @@ fn format_timestamp(t: Timestamp, timefmt: &str) -> DelayedFormat<StrftimeItems<
 fn format_timeslot<TW:TimeConvex>(tw: &TW, timefmt: &str) -> String
     where TW::TimePoint: TimePointFormat
 {
-    if tw.is_empty() {
-        "{{}}".to_string()
-
-    } else if tw.is_singleton() {
-        format!("{{{}}}", tw.lower_bound().format_timepoint(timefmt))
-
-    } else if tw.is_low_bounded() {
-        if tw.is_up_bounded() {
-            format!("[{},{}]",tw.lower_bound().format_timepoint(timefmt), tw.upper_bound().format_timepoint(timefmt))
-        } else {
-            format!("[{},+oo[", tw.lower_bound().format_timepoint(timefmt))
+    match (tw.checked_lower_bound(), tw.checked_upper_bound()) {
+        (Some(lower), Some(upper)) if lower == upper => {
+            format!("{{{}}}", lower.format_timepoint(timefmt))
         }
-    } else if tw.is_up_bounded() {
-        format!("]-oo,{}]", tw.upper_bound().format_timepoint(timefmt))
-    } else {
-        "]-oo,+oo[".to_string()
+        (Some(lower), Some(upper)) if lower.is_finite() => {
+            if upper.is_finite() {
+                format!("[{},{}]", lower.format_timepoint(timefmt), upper.format_timepoint(timefmt))
+            } else {
+                format!("[{},+oo[", lower.format_timepoint(timefmt))
+            }
+        }
+        (Some(_), Some(upper)) if upper.is_finite() => {
+            format!("]-oo,{}]", upper.format_timepoint(timefmt))
+        }
+        (Some(_), Some(_)) => "]-oo,+oo[".to_string(),
+        _ => "{}".to_string() /* empty set */
     }
 }
 
@@ -114,7 +197,7 @@ impl<TW:TimeWindow> TimeSetFormat for TW
             iter.fold(format_timeslot(&first, timefmt),
                       |s,i| s + "U" + &format_timeslot(&i,timefmt))
         } else {
-           "{{}}".to_string() /* empty set */
+           "{}".to_string() /* empty set */
         }
     }
 }
@@ -143,6 +226,9 @@ impl Debug for TimeValue
 }
 
 
+/// Displays a duration with its units (e.g. `2d 3h 15min`)
+///
+/// The alternate form (`{:#}`) is more compact (e.g. `2d3h15m`).
 impl fmt::Display for TimeValue
 {
     #[allow(clippy::collapsible_else_if)]
@@ -152,13 +238,15 @@ impl fmt::Display for TimeValue
             if self.is_future_infinite() {
                 write!(formatter, "+oo")
             } else {
-                write!(formatter, "{}", format_duration(self.as_ticks()))
+                write!(formatter, "{}", format_duration(self.as_ticks(), formatter.alternate(), false))
             }
         } else {
             if self.is_past_infinite() {
                 write!(formatter, "-oo")
+            } else if formatter.alternate() {
+                write!(formatter, "-{}", format_duration(-self.as_ticks(), true, false))
             } else {
-                write!(formatter, "- {}", format_duration(-self.as_ticks()))
+                write!(formatter, "- {}", format_duration(-self.as_ticks(), false, false))
             }
         }
     }
@@ -181,7 +269,10 @@ impl fmt::Display for Timestamp
             if self.is_future_infinite() {
                 write!(formatter, "+oo")
             } else {
-                write!(formatter, "{}", self.to_datetime())
+                #[cfg(feature = "std")]
+                { write!(formatter, "{}", self.to_datetime()) }
+                #[cfg(not(feature = "std"))]
+                { write!(formatter, "{} after 1970-01-01 00:00:00 UTC", self.0) }
             }
         } else {
             if self.is_past_infinite() {
@@ -220,6 +311,9 @@ impl<T:TimePoint+fmt::Debug> fmt::Debug for TimeInterval<T>
 }
 
 
+/// Displays an interval with its bounds
+///
+/// The formatting flags (e.g. the alternate form `{:#}`) are passed to the bounds.
 impl<T:TimePoint+fmt::Display> fmt::Display for TimeInterval<T>
 {
     fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result
@@ -228,16 +322,24 @@ impl<T:TimePoint+fmt::Display> fmt::Display for TimeInterval<T>
             write!(formatter, "{{}}")
 
         } else if self.is_singleton() {
-            write!(formatter, "{{{}}}", self.lower)
+            write!(formatter, "{{")?;
+            fmt::Display::fmt(&self.lower, formatter)?;
+            write!(formatter, "}}")
 
         } else if self.is_low_bounded() {
+            write!(formatter, "[")?;
+            fmt::Display::fmt(&self.lower, formatter)?;
             if self.is_up_bounded() {
-                write!(formatter, "[{},{}]", self.lower, self.upper)
+                write!(formatter, ",")?;
+                fmt::Display::fmt(&self.upper, formatter)?;
+                write!(formatter, "]")
             } else {
-                write!(formatter, "[{},+oo[", self.lower)
+                write!(formatter, ",+oo[")
             }
         } else if self.is_up_bounded() {
-            write!(formatter, "]-oo,{}]", self.upper)
+            write!(formatter, "]-oo,")?;
+            fmt::Display::fmt(&self.upper, formatter)?;
+            write!(formatter, "]")
         } else {
             write!(formatter, "]-oo,+oo[")
         }
@@ -266,10 +368,80 @@ impl<T:TimePoint+fmt::Display> fmt::Display for TimeSet<T>
     {
         let mut iter = self.0.iter();
         if let Some(first) = iter.next() {
-            write!(formatter, "{}", first)?;
-            iter.try_for_each(|tw| write!(formatter, "U{}", tw))
+            fmt::Display::fmt(first, formatter)?;
+            iter.try_for_each(|tw| { write!(formatter, "U")?; fmt::Display::fmt(tw, formatter) })
         } else {
             write!(formatter, "{{}}") /* empty set */
         }
     }
-}
\ No newline at end of file
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn relative_display()
+    {
+        assert_eq!( TimeValue::from_secs(10).format_relative(), "just now");
+        assert_eq!( TimeValue::from_secs(30).format_relative(), "1 minute");
+        assert_eq!( TimeValue::from_mins(5).format_relative(), "5 minutes");
+        assert_eq!( TimeValue::from_secs(59*60+40).format_relative(), "1 hour");
+        assert_eq!( (TimeValue::from_hours(1) + TimeValue::from_mins(40)).format_relative(), "2 hours");
+        assert_eq!( TimeValue::from_hours(23).format_relative(), "23 hours");
+        assert_eq!( (TimeValue::from_hours(23) + TimeValue::from_mins(30)).format_relative(), "1 day");
+        assert_eq!( TimeValue::from_days(3).format_relative(), "3 days");
+        assert_eq!( (-TimeValue::from_days(3)).format_relative(), "3 days");
+        assert_eq!( TimeValue::INFINITE.format_relative(), "forever");
+
+        let now = Timestamp::from_unix_secs(1_700_000_000);
+        assert_eq!( (now - TimeValue::from_hours(2)).format_relative_to(now), "2 hours ago");
+        assert_eq!( (now + TimeValue::from_days(3)).format_relative_to(now), "3 days from now");
+        assert_eq!( (now + TimeValue::from_secs(5)).format_relative_to(now), "just now");
+        assert_eq!( Timestamp::INFINITE.format_relative_to(now), "never");
+        assert_eq!( (-Timestamp::INFINITE).format_relative_to(now), "forever");
+    }
+
+    #[test]
+    fn compact_display()
+    {
+        let t = TimeValue::from_days(2) + TimeValue::from_hours(3) + TimeValue::from_mins(15);
+        assert_eq!( format!("{}", t), "2d 3h 15min");
+        assert_eq!( format!("{:#}", t), "2d3h15m");
+        assert_eq!( format!("{:#}", -t), "-2d3h15m");
+        assert_eq!( format!("{:#}", TimeValue::from_millis(1500)), "1s500ms");
+        assert_eq!( format!("{:#}", TimeValue::default()), "0");
+        assert_eq!( format!("{:#}", TimeValue::INFINITE), "+oo");
+        assert_eq!( format!("{:#}", t).parse::<TimeValue>(), Ok(t));
+
+        let span = TimeSpan::new(TimeValue::from_mins(90), t);
+        assert_eq!( format!("{}", span), "[1h 30min,2d 3h 15min]");
+        assert_eq!( format!("{:#}", span), "[1h30m,2d3h15m]");
+        assert_eq!( format!("{:#}", TimeSpan::after(t)), "[2d3h15m,+oo[");
+        assert_eq!( format!("{:#}", TimeSpan::before(-t)), "]-oo,-2d3h15m]");
+        assert_eq!( format!("{:#}", TimeSpan::singleton(t)), "{2d3h15m}");
+        assert_eq!( format!("{:#}", span | TimeSpan::singleton(TimeValue::from_days(3))), "[1h30m,2d3h15m]U{3d}");
+    }
+
+    #[test]
+    fn ticks_format()
+    {
+        let t = TimeValue::from_millis(1500) + TimeValue::from_ticks(1);
+        assert_eq!( t.format_timepoint(""), "1s 500ms 1ns");
+        assert_eq!( t.format_timepoint("%t"), "1s 500ms 1ns");
+        assert_eq!( TimeValue::from_ticks(1000).format_timepoint(""), "931ns");
+        assert_eq!( TimeValue::from_ticks(1000).format_timepoint("%t"), "931ns 1t");
+        assert_eq!( TimeValue::from_ticks(1_073_741_823).format_timepoint("%t"), "999ms 999us 999ns 1t");
+        assert_eq!( TimeValue::from_secs(2).format_timepoint("%t"), "2s");
+        assert_eq!( (-TimeValue::from_ticks(1000)).format_timepoint("%t"), "- 931ns 1t");
+
+        // exact round trips
+        for n in [1, 2, 3, 7, 1_000, 123_456_789, 1_073_741_823, 1_073_741_825, 987_654_321_987] {
+            let t = TimeValue::from_ticks(n);
+            assert_eq!( t.format_timepoint("%t").parse::<TimeValue>(), Ok(t), "round trip of {} ticks", n);
+        }
+        assert_eq!( "123t".parse::<TimeValue>(), Ok(TimeValue::from_ticks(123)));
+        assert_eq!( "1s 3t".parse::<TimeValue>(), Ok(TimeValue::from_secs(1) + TimeValue::from_ticks(3)));
+    }
+}
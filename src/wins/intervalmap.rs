@@ -0,0 +1,233 @@
+use core::ops::Bound::Included;
+use alloc::collections::BTreeMap;
+use crate::*;
+
+/// # A map from disjoint time intervals to values
+///
+/// Each interval (the key) is associated to one value and the
+/// intervals of a map never overlap: inserting a new interval
+/// overwrites the previous values on this interval and the
+/// intervals which straddle it are split.
+///
+/// The map is kept as compact as possible: two adjacent intervals
+/// mapped to the same value are merged into one.
+///
+/// ```
+/// # use chronologic::*;
+/// let h = TimeValue::from_hours;
+/// let mut shifts = IntervalMap::new();
+/// shifts.insert(TimeSpan::new(h(0), h(24)), "alice");
+/// shifts.insert(TimeSpan::new(h(8), h(12)), "bob");
+///
+/// assert_eq!( shifts.len(), 3);
+/// assert_eq!( shifts.get(h(10)), Some(&"bob"));
+/// assert_eq!( shifts.get(h(14)), Some(&"alice"));
+/// assert_eq!( shifts.get(h(30)), None);
+/// ```
+#[derive(Clone, Eq, PartialEq, Hash)]
+pub struct IntervalMap<T:TimePoint,V>(BTreeMap<T,(T,V)>);
+
+impl<T:TimePoint,V> Default for IntervalMap<T,V> {
+    #[inline] fn default() -> Self { Self::new() }
+}
+
+impl<T:TimePoint,V> IntervalMap<T,V>
+{
+    /// Creates an empty map
+    #[inline]
+    pub fn new() -> Self { Self(BTreeMap::new()) }
+
+    /// The number of intervals of this map
+    #[inline]
+    pub fn len(&self) -> usize { self.0.len() }
+
+    /// Checks if this map has no interval
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.0.is_empty() }
+
+    /// Removes all the intervals of this map
+    #[inline]
+    pub fn clear(&mut self) { self.0.clear() }
+
+    /// Gets the value associated to a time point, if any
+    #[inline]
+    pub fn get(&self, point: T) -> Option<&V>
+    {
+        self.0.range(..=point).next_back()
+            .filter(|(_, (upper, _))| point <= *upper)
+            .map(|(_, (_, value))| value)
+    }
+
+    /// Iterates over all the intervals of this map (in chronological order)
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item=(TimeInterval<T>,&V)> + '_
+    {
+        self.0.iter()
+            .map(|(&lower, (upper, value))| (TimeInterval { lower, upper: *upper }, value))
+    }
+
+    /// Iterates over the intervals of this map which overlap a given interval
+    ///
+    /// The intervals are given entirely, as stored in the map (i.e. they are not
+    /// truncated to the given interval).
+    pub fn range(&self, interval: TimeInterval<T>) -> impl Iterator<Item=(TimeInterval<T>,&V)> + '_
+    {
+        let bounds = (!interval.is_empty()).then(|| {
+            let lower = self.0.range(..interval.lower_bound()).next_back()
+                .filter(|(_, (upper, _))| interval.lower_bound() <= *upper)
+                .map_or(interval.lower_bound(), |(&lower, _)| lower);
+            (lower, interval.upper_bound())
+        });
+        bounds.into_iter()
+            .flat_map(move |(lower, upper)| self.0.range((Included(lower), Included(upper))))
+            .map(|(&lower, (upper, value))| (TimeInterval { lower, upper: *upper }, value))
+    }
+}
+
+impl<T:TimePoint,V:Clone+PartialEq> IntervalMap<T,V>
+{
+    /// Associates a value to each point of an interval
+    ///
+    /// The previous values on this interval are overwritten: the intervals
+    /// which are partly covered are split and keep their value outside of
+    /// the inserted interval. Then, the inserted interval is merged with its
+    /// adjacent intervals if they have the same value.
+    ///
+    /// Inserting an empty interval does nothing.
+    pub fn insert(&mut self, interval: TimeInterval<T>, value: V)
+    {
+        if interval.is_empty() { return; }
+        let (mut lower, mut upper) = (interval.lower_bound(), interval.upper_bound());
+
+        // the previous interval which straddles the lower bound is split
+        if let Some((&before, (after, _))) = self.0.range(..lower).next_back() {
+            if lower <= *after {
+                let (after, previous) = self.0.remove(&before).unwrap();
+                if upper < after {
+                    self.0.insert(upper.just_after(), (after, previous.clone()));
+                }
+                self.0.insert(before, (lower.just_before(), previous));
+            }
+        }
+
+        // the intervals which start inside are removed
+        // (and the last one is truncated if it goes beyond the upper bound)
+        let inside = self.0.range((Included(lower), Included(upper)))
+            .map(|(&k, _)| k)
+            .collect::<Vec<_>>();
+        for k in inside {
+            let (after, previous) = self.0.remove(&k).unwrap();
+            if upper < after {
+                self.0.insert(upper.just_after(), (after, previous));
+            }
+        }
+
+        // merges with the adjacent intervals of the same value
+        if let Some((&before, (after, previous))) = self.0.range(..lower).next_back() {
+            if after.just_after() == lower && *previous == value {
+                self.0.remove(&before);
+                lower = before;
+            }
+        }
+        if upper.is_finite() {
+            if let Some((after, next)) = self.0.get(&upper.just_after()) {
+                if *next == value {
+                    let k = upper.just_after();
+                    upper = *after;
+                    self.0.remove(&k);
+                }
+            }
+        }
+        self.0.insert(lower, (upper, value));
+    }
+}
+
+impl<T:TimePoint,V:Clone+PartialEq> FromIterator<(TimeInterval<T>,V)> for IntervalMap<T,V>
+{
+    /// Builds a map by inserting the intervals in order
+    /// (so the last ones overwrite the first ones)
+    fn from_iter<I: IntoIterator<Item=(TimeInterval<T>,V)>>(iter: I) -> Self
+    {
+        let mut map = Self::new();
+        iter.into_iter().for_each(|(tw, value)| map.insert(tw, value));
+        map
+    }
+}
+
+impl<T:TimePoint,V:core::fmt::Debug> core::fmt::Debug for IntervalMap<T,V>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn insert()
+    {
+        let t = TimeValue::from_ticks;
+        let mut map = IntervalMap::new();
+        map.insert(TimeSpan::new(t(0), t(100)), 'a');
+        map.insert(TimeSpan::new(t(20), t(30)), 'b');
+        map.insert(TimeSpan::new(t(60), t(200)), 'c');
+        assert_eq!( map.iter().collect::<Vec<_>>(), vec![
+            (TimeSpan::new(t(0), t(19)), &'a'),
+            (TimeSpan::new(t(20), t(30)), &'b'),
+            (TimeSpan::new(t(31), t(59)), &'a'),
+            (TimeSpan::new(t(60), t(200)), &'c'),
+        ]);
+
+        assert_eq!( map.get(t(19)), Some(&'a'));
+        assert_eq!( map.get(t(20)), Some(&'b'));
+        assert_eq!( map.get(t(200)), Some(&'c'));
+        assert_eq!( map.get(t(201)), None);
+        assert_eq!( map.get(t(-1)), None);
+
+        // overwrites several intervals and merges with the adjacent ones
+        map.insert(TimeSpan::new(t(10), t(40)), 'a');
+        assert_eq!( map.iter().collect::<Vec<_>>(), vec![
+            (TimeSpan::new(t(0), t(59)), &'a'),
+            (TimeSpan::new(t(60), t(200)), &'c'),
+        ]);
+        map.insert(TimeSpan::new(t(201), t(300)), 'c');
+        map.insert(TimeSpan::empty(), 'z');
+        assert_eq!( map.len(), 2);
+        assert_eq!( map.get(t(300)), Some(&'c'));
+
+        // unbounded intervals
+        map.insert(TimeSpan::after(t(250)), 'd');
+        map.insert(TimeSpan::before(t(-1)), 'a');
+        assert_eq!( map.iter().collect::<Vec<_>>(), vec![
+            (TimeSpan::before(t(59)), &'a'),
+            (TimeSpan::new(t(60), t(249)), &'c'),
+            (TimeSpan::after(t(250)), &'d'),
+        ]);
+        map.insert(TimeSpan::all(), 'e');
+        assert_eq!( map.iter().collect::<Vec<_>>(), vec![(TimeSpan::all(), &'e')]);
+    }
+
+    #[test]
+    fn range()
+    {
+        let t = TimeValue::from_ticks;
+        let map = [
+            (TimeSpan::new(t(0), t(9)), 1),
+            (TimeSpan::new(t(10), t(19)), 2),
+            (TimeSpan::new(t(30), t(39)), 3),
+        ].into_iter().collect::<IntervalMap<_,_>>();
+
+        let values = |tw| map.range(tw).map(|(_, v)| *v).collect::<Vec<_>>();
+        assert_eq!( values(TimeSpan::new(t(5), t(12))), vec![1, 2]);
+        assert_eq!( values(TimeSpan::new(t(9), t(10))), vec![1, 2]);
+        assert_eq!( values(TimeSpan::new(t(20), t(29))), Vec::<i32>::new());
+        assert_eq!( values(TimeSpan::singleton(t(39))), vec![3]);
+        assert_eq!( values(TimeSpan::all()), vec![1, 2, 3]);
+        assert_eq!( values(TimeSpan::empty()), Vec::<i32>::new());
+        assert_eq!( map.range(TimeSpan::new(t(15), t(35))).next(), Some((TimeSpan::new(t(10), t(19)), &2)));
+    }
+}
@@ -0,0 +1,161 @@
+use crate::*;
+
+
+impl TimeValue {
+
+    /// Formats the duration in ISO 8601 (e.g. `P2DT3H15M` or `-PT0.5S`)
+    ///
+    /// The duration is expressed in days, hours, minutes and seconds
+    /// (years and months are not used since they have no exact duration).
+    /// The fractional part of the seconds is given to the nanosecond
+    /// (see [`Self::from_iso8601`] for the round trip).
+    ///
+    /// An infinite duration has no ISO 8601 representation, so an overflow
+    /// error is returned.
+    pub fn to_iso8601(&self) -> TimeResult<String>
+    {
+        if self.is_future_infinite() {
+            return Err(TimeError::FutureOverflow);
+        }
+        if self.is_past_infinite() {
+            return Err(TimeError::PastOverflow);
+        }
+        let ticks = self.0.unsigned_abs();
+        let secs = ticks >> SUBSEC_BITLEN;
+        // rounded to the nearest nanosecond (less than 1e9 since a tick is less than 1ns)
+        let nanos = (((ticks & SUBSEC_BITMASK as u64) * 1_000_000_000 + (1 << (SUBSEC_BITLEN-1))) >> SUBSEC_BITLEN) as u32;
+        let (days, hours, mins, secs) = (secs / 86400, secs / 3600 % 24, secs / 60 % 60, secs % 60);
+
+        let mut iso = String::from(if self.is_strictly_negative() { "-P" } else { "P" });
+        if days > 0 {
+            iso += &format!("{}D", days);
+        }
+        if hours > 0 || mins > 0 || secs > 0 || nanos > 0 || days == 0 {
+            iso.push('T');
+            if hours > 0 { iso += &format!("{}H", hours); }
+            if mins > 0 { iso += &format!("{}M", mins); }
+            if nanos > 0 {
+                iso += format!("{}.{:09}", secs, nanos).trim_end_matches('0');
+                iso.push('S');
+            } else if secs > 0 || (days == 0 && hours == 0 && mins == 0) {
+                iso += &format!("{}S", secs);
+            }
+        }
+        Ok(iso)
+    }
+
+    /// Parses an ISO 8601 duration (e.g. `P1Y2M10DT2H30M`, `PT0.25S` or `-P3W`)
+    ///
+    /// Years and months are converted as in [`Self::from_years`] and
+    /// [`Self::from_months`]. Only the seconds could have a fractional part
+    /// (with `.` or `,`), which is rounded to the nearest tick: so the result
+    /// of [`Self::to_iso8601`] is parsed back to a duration with the same
+    /// representation.
+    pub fn from_iso8601(s: &str) -> Result<TimeValue, ParseError>
+    {
+        let err = |msg: &str| ParseError::new(format!("{} in ISO 8601 duration `{}`", msg, s));
+        let (negative, rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s))
+        };
+        let rest = rest.strip_prefix('P').ok_or_else(|| err("missing `P`"))?;
+        let (date, time) = match rest.split_once('T') {
+            Some((_, "")) => return Err(err("missing time after `T`")),
+            Some((date, time)) => (date, Some(time)),
+            None => (rest, None)
+        };
+        if date.is_empty() && time.is_none() {
+            return Err(err("missing duration"));
+        }
+
+        let mut result = TimeValue::default();
+        for (part, designators, is_time) in [(date, "YMWD", false), (time.unwrap_or(""), "HMS", true)] {
+            let mut part = part;
+            let mut designators = designators;
+            while !part.is_empty() {
+                let len = part.find(|c:char| !c.is_ascii_digit() && c != '.' && c != ',')
+                    .ok_or_else(|| err("missing designator"))?;
+                let (number, designator) = (&part[..len], part[len..].chars().next().unwrap());
+                // the designators should appear in order (and only once)
+                let pos = designators.find(designator).ok_or_else(|| err("unexpected designator"))?;
+                designators = &designators[pos+1..];
+                part = &part[len+1..];
+
+                let (whole, fract) = match number.split_once(['.', ',']) {
+                    Some((whole, fract)) if designator == 'S' && designators.is_empty() => (whole, fract),
+                    Some(_) => return Err(err("fractional part allowed only for seconds")),
+                    None => (number, "")
+                };
+                if whole.is_empty() || !fract.bytes().all(|c| c.is_ascii_digit()) {
+                    return Err(err("invalid number"));
+                }
+                let n = whole.parse::<i64>().map_err(|_| err("number too large"))?;
+                result += match (designator, is_time) {
+                    ('Y', _) => TimeValue::from_years(n),
+                    ('M', false) => TimeValue::from_months(n),
+                    ('W', _) => TimeValue::from_weeks(n),
+                    ('D', _) => TimeValue::from_days(n),
+                    ('H', _) => TimeValue::from_hours(n),
+                    ('M', true) => TimeValue::from_mins(n),
+                    _ => TimeValue::from_secs(n) + fract_to_ticks(fract)
+                };
+            }
+        }
+        if !result.is_finite() {
+            Err(err("overflow"))
+        } else {
+            Ok(if negative { -result } else { result })
+        }
+    }
+}
+
+// the decimal fraction of a second, rounded to the nearest tick
+fn fract_to_ticks(digits: &str) -> TimeValue
+{
+    // beyond 18 digits, the precision is far below the tick
+    let digits = &digits[..digits.len().min(18)];
+    let unit = 10_i128.pow(digits.len() as u32);
+    let n = digits.parse::<i128>().unwrap_or(0);
+    TimeValue::from_ticks((((n << SUBSEC_BITLEN) + unit/2) / unit) as i64)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn iso8601()
+    {
+        let t = TimeValue::from_days(2) + TimeValue::from_hours(3) + TimeValue::from_mins(15);
+        assert_eq!( t.to_iso8601(), Ok("P2DT3H15M".to_string()));
+        assert_eq!( (-t).to_iso8601(), Ok("-P2DT3H15M".to_string()));
+        assert_eq!( TimeValue::from_days(3).to_iso8601(), Ok("P3D".to_string()));
+        assert_eq!( TimeValue::from_mins(61).to_iso8601(), Ok("PT1H1M".to_string()));
+        assert_eq!( TimeValue::from_millis(-1500).to_iso8601(), Ok("-PT1.5S".to_string()));
+        assert_eq!( TimeValue::default().to_iso8601(), Ok("PT0S".to_string()));
+        assert_eq!( TimeValue::INFINITE.to_iso8601(), Err(TimeError::FutureOverflow));
+        assert_eq!( (-TimeValue::INFINITE).to_iso8601(), Err(TimeError::PastOverflow));
+
+        assert_eq!( TimeValue::from_iso8601("P2DT3H15M"), Ok(t));
+        assert_eq!( TimeValue::from_iso8601("-P2DT3H15M"), Ok(-t));
+        assert_eq!( TimeValue::from_iso8601("P1Y2M3W"), Ok(TimeValue::from_years(1) + TimeValue::from_months(2) + TimeValue::from_weeks(3)));
+        assert_eq!( TimeValue::from_iso8601("PT2M"), Ok(TimeValue::from_mins(2)));
+        assert_eq!( TimeValue::from_iso8601("P2MT2M"), Ok(TimeValue::from_months(2) + TimeValue::from_mins(2)));
+        assert_eq!( TimeValue::from_iso8601("PT0.25S"), Ok(TimeValue::from_millis(250)));
+        assert_eq!( TimeValue::from_iso8601("PT1,5S"), Ok(TimeValue::from_millis(1500)));
+        assert_eq!( TimeValue::from_iso8601("PT0S"), Ok(TimeValue::default()));
+
+        // stable round trips
+        for ns in [1, 7, 12, 999_999_999, 123_456_789, 1_000_000_001] {
+            let iso = TimeValue::from_nanos(ns).to_iso8601().unwrap();
+            assert_eq!( TimeValue::from_iso8601(&iso).unwrap().to_iso8601().unwrap(), iso);
+        }
+        let t = TimeValue::from_ticks(123_456_789_123);
+        assert_eq!( TimeValue::from_iso8601(&t.to_iso8601().unwrap()).unwrap().to_iso8601(), t.to_iso8601());
+
+        for s in ["", "P", "2D", "PT", "P2", "PT3H2H", "PT2M3H", "P1.5D", "PT1.5M2S", "PxD", "P2DT", "P2S", "PT99999999999999999999S"] {
+            assert!( TimeValue::from_iso8601(s).is_err(), "{}", s);
+        }
+    }
+}
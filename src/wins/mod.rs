@@ -2,15 +2,25 @@ mod timestamp;
 mod timevalue;
 mod timeinterval;
 mod timeset;
+mod intervalmap;
 mod format;
 mod convert;
+mod parse;
+mod iso8601;
+mod clock;
+#[cfg(feature = "std")]
+mod calendar;
 
-pub use timevalue::TimeValue;
+pub use timevalue::{TimeValue,TimeValueAccumulator,lcm};
 pub use timestamp::{Timestamp,Timestamped};
 pub use timeinterval::*;
 pub use timeset::*;
+pub use intervalmap::IntervalMap;
 pub use format::{TimeSetFormat,TimePointFormat};
 pub use convert::IntoTimeValue;
+pub use clock::*;
+#[cfg(feature = "std")]
+pub use calendar::WorkingHours;
 use crate::iter::TimeConvexIterator;
 
 
@@ -65,6 +75,24 @@ pub trait TimeBounds {
     ///
     /// The behavior is undefined if the time window is empty
     fn upper_bound(&self) -> Self::TimePoint;
+
+    /// The lower bound of the time window, if any
+    ///
+    /// This is the same as [`Self::lower_bound`] but `None` is
+    /// returned if the time window is empty.
+    #[inline]
+    fn checked_lower_bound(&self) -> Option<Self::TimePoint> {
+        (!self.is_empty()).then(|| self.lower_bound())
+    }
+
+    /// The upper bound of the time window, if any
+    ///
+    /// This is the same as [`Self::upper_bound`] but `None` is
+    /// returned if the time window is empty.
+    #[inline]
+    fn checked_upper_bound(&self) -> Option<Self::TimePoint> {
+        (!self.is_empty()).then(|| self.upper_bound())
+    }
 }
 
 /// # An arbitrary set of timepoints
@@ -129,7 +157,7 @@ impl<TW:TimeConvex> TimeWindow for TW {
         if self.is_empty() { 0 } else { 1 }
     }
 
-    type ConvexIter = std::option::IntoIter<TimeInterval<Self::TimePoint>>;
+    type ConvexIter = core::option::IntoIter<TimeInterval<Self::TimePoint>>;
 
     #[inline]
     fn iter(&self) -> Self::ConvexIter
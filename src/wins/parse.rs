@@ -0,0 +1,156 @@
+use core::str::FromStr;
+use crate::*;
+
+
+impl FromStr for TimeValue
+{
+    type Err = ParseError;
+
+    /// Parses a duration as displayed (e.g. `1d 2h 30min`, `- 10ms`, `+oo`)
+    ///
+    /// A duration is a sequence of integers, each one followed by its unit:
+    /// `y` (years), `mo` (months), `w` (weeks), `d` (days), `h` (hours),
+    /// `min` or `m` (minutes), `s` (seconds), `ms` (milliseconds), `us` (microseconds),
+    /// `ns` (nanoseconds) and `t` (clock ticks). The spaces between the parts are optional.
+    ///
+    /// A number could also have a fractional part (e.g. `1.5h` or `0.25d`):
+    /// the duration is then computed with floats and rounded to the nearest tick.
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let s = s.trim();
+        match s {
+            "+oo" | "oo" => return Ok(TimeValue::INFINITE),
+            "-oo" => return Ok(-TimeValue::INFINITE),
+            "0" => return Ok(TimeValue::default()),
+            _ => { }
+        }
+        let (negative, mut rest) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest.trim_start()),
+            None => (false, s.strip_prefix('+').unwrap_or(s).trim_start())
+        };
+        if rest.is_empty() {
+            return Err(ParseError::new(format!("missing duration in `{}`", s)));
+        }
+        let mut result = TimeValue::default();
+        while !rest.is_empty() {
+            let digits = rest.find(|c:char| !c.is_ascii_digit() && c != '.').unwrap_or(rest.len());
+            let number = &rest[..digits];
+            if digits == 0 || number.starts_with('.') || number.ends_with('.') {
+                return Err(ParseError::new(format!("number expected in `{}`", s)));
+            }
+            rest = &rest[digits..];
+            let len = rest.find(|c:char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+            let (unit, secs): (fn(i64) -> TimeValue, f64) = match &rest[..len] {
+                "y" => (TimeValue::from_years, 146097.*24.*3600./400.),
+                "mo" => (TimeValue::from_months, (146097_i64*24*3600/400/12) as f64),
+                "w" => (TimeValue::from_weeks, 7.*24.*3600.),
+                "d" => (TimeValue::from_days, 24.*3600.),
+                "h" => (TimeValue::from_hours, 3600.),
+                "min" | "m" => (TimeValue::from_mins, 60.),
+                "s" => (TimeValue::from_secs, 1.),
+                "ms" => (TimeValue::from_millis, 1e-3),
+                "us" => (TimeValue::from_micros, 1e-6),
+                "ns" => (TimeValue::from_nanos, 1e-9),
+                "t" => (TimeValue::from_ticks, 1. / (1_i64 << SUBSEC_BITLEN) as f64),
+                "" => return Err(ParseError::new(format!("missing time unit in `{}`", s))),
+                unit => return Err(ParseError::new(format!("unknown time unit `{}` in `{}`", unit, s)))
+            };
+            result += if number.contains('.') {
+                let x = number.parse::<f64>()
+                    .map_err(|_| ParseError::new(format!("invalid number `{}` in `{}`", number, s)))?;
+                TimeValue::from_secs(1) * (x * secs)
+            } else {
+                unit(number.parse::<i64>()
+                    .map_err(|_| ParseError::new(format!("number too large in `{}`", s)))?)
+            };
+            rest = rest[len..].trim_start();
+        }
+        Ok(if negative { -result } else { result })
+    }
+}
+
+
+impl FromStr for TimeSpan
+{
+    type Err = ParseError;
+
+    /// Parses a time span as displayed (e.g. `[1s,5s]`, `]-oo,2h]`, `{3ms}` or `{}`)
+    fn from_str(s: &str) -> Result<Self, Self::Err>
+    {
+        let s = s.trim();
+        if s == "{}" {
+            Ok(TimeSpan::empty())
+        } else if let Some(t) = s.strip_prefix('{').and_then(|s| s.strip_suffix('}')) {
+            Ok(TimeSpan::singleton(t.parse()?))
+        } else {
+            let err = || ParseError::new(format!("invalid time span `{}`", s));
+            let (lower, upper) = s.get(1..s.len().saturating_sub(1))
+                .and_then(|inner| inner.split_once(','))
+                .ok_or_else(err)?;
+            let lower = match s.as_bytes()[0] {
+                b'[' => lower.parse()?,
+                b']' if lower.trim() == "-oo" => -TimeValue::INFINITE,
+                _ => return Err(err())
+            };
+            let upper = match s.as_bytes()[s.len()-1] {
+                b']' => upper.parse()?,
+                b'[' if matches!(upper.trim(), "+oo" | "oo") => TimeValue::INFINITE,
+                _ => return Err(err())
+            };
+            if lower > upper {
+                Err(ParseError::new(format!("lower bound greater than upper bound in `{}`", s)))
+            } else {
+                Ok(TimeSpan::new(lower, upper))
+            }
+        }
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn parse_timevalue()
+    {
+        let t = TimeValue::from_secs(7*86400 + 5*3600 + 7*60 + 4);
+        assert_eq!( t.to_string().parse::<TimeValue>(), Ok(t) );
+        assert_eq!( (-t).to_string().parse::<TimeValue>(), Ok(-t) );
+        assert_eq!( "1h30min".parse::<TimeValue>(), Ok(TimeValue::from_mins(90)) );
+        assert_eq!( "10ms".parse::<TimeValue>(), Ok(TimeValue::from_millis(10)) );
+        assert_eq!( "0".parse::<TimeValue>(), Ok(TimeValue::default()) );
+        assert_eq!( "+oo".parse::<TimeValue>(), Ok(TimeValue::INFINITE) );
+        assert_eq!( "-oo".parse::<TimeValue>(), Ok(-TimeValue::INFINITE) );
+
+        assert_eq!( "1.5h".parse::<TimeValue>(), Ok(TimeValue::from_mins(90)) );
+        assert_eq!( "0.25d".parse::<TimeValue>(), Ok(TimeValue::from_hours(6)) );
+        assert_eq!( "2.5s".parse::<TimeValue>(), Ok(TimeValue::from_millis(2500)) );
+        assert_eq!( "- 1.5min 2.0s".parse::<TimeValue>(), Ok(-TimeValue::from_secs(92)) );
+        assert_eq!( "0.5ms".parse::<TimeValue>(), Ok(TimeValue::from_secs_f64(0.0005)) );
+
+        assert!( "".parse::<TimeValue>().is_err() );
+        assert!( "1.h".parse::<TimeValue>().is_err() );
+        assert!( ".5h".parse::<TimeValue>().is_err() );
+        assert!( "1.2.3h".parse::<TimeValue>().is_err() );
+        assert!( "12".parse::<TimeValue>().is_err() );
+        assert!( "12 parsecs".parse::<TimeValue>().is_err() );
+        assert!( "h".parse::<TimeValue>().is_err() );
+    }
+
+    #[test]
+    fn parse_timespan()
+    {
+        let t = TimeValue::from_secs;
+        for tw in [TimeSpan::new(t(1), t(5)), TimeSpan::singleton(t(3)), TimeSpan::after(t(2)),
+                   TimeSpan::before(-t(2)), TimeSpan::all(), TimeSpan::empty()] {
+            assert_eq!( tw.to_string().parse::<TimeSpan>(), Ok(tw) );
+        }
+        assert_eq!( "[ 1s , 2min ]".parse::<TimeSpan>(), Ok(TimeSpan::new(t(1), t(120))) );
+
+        assert!( "[1s,2s".parse::<TimeSpan>().is_err() );
+        assert!( "]1s,2s]".parse::<TimeSpan>().is_err() );
+        assert!( "[2s,1s]".parse::<TimeSpan>().is_err() );
+        assert!( "[".parse::<TimeSpan>().is_err() );
+    }
+}
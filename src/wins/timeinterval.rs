@@ -1,5 +1,5 @@
-use std::cmp::Ordering;
-use std::ops::{Add, Neg, Sub};
+use core::cmp::Ordering;
+use core::ops::{Add, Bound, Neg, RangeInclusive, Sub};
 use super::*;
 use crate::*;
 
@@ -115,6 +115,162 @@ impl<T:TimePoint> TimeInterval<T>
     {
         Self { lower: -T::INFINITE, upper: T::INFINITE }
     }
+
+    /// Converts a bounded interval into an inclusive range `lower..=upper`
+    ///
+    /// Returns `None` if the interval is empty or unbounded.
+    /// This is the inverse of the conversion from [`RangeInclusive`].
+    #[inline]
+    pub fn as_range(&self) -> Option<RangeInclusive<T>>
+    {
+        if self.is_empty() || !self.is_bounded() { None } else { Some(self.lower..=self.upper) }
+    }
+
+    /// Converts this interval into a couple of bounds
+    ///
+    /// A finite bound is `Included` and an infinite one is `Unbounded`.
+    /// Since a couple of bounds implements [`core::ops::RangeBounds`], the result
+    /// could be used with the APIs based on ranges (e.g. `BTreeMap::range`).
+    ///
+    /// For an empty interval, both bounds are included but the lower
+    /// one is greater than the upper one, so it is an empty range
+    /// (notice that `BTreeMap::range` panics with such a range).
+    #[inline]
+    pub fn to_bounds(&self) -> (Bound<T>, Bound<T>)
+    {
+        if self.is_empty() {
+            (Bound::Included(self.lower), Bound::Included(self.upper))
+        } else {
+            let bound = |t: T| if t.is_finite() { Bound::Included(t) } else { Bound::Unbounded };
+            (bound(self.lower), bound(self.upper))
+        }
+    }
+
+    /// Creates an interval from a couple of bounds
+    ///
+    /// Since time is discrete, an excluded bound is converted to the closest
+    /// included one (i.e. `]a,b[` is `[a+1,b-1]`). This is the inverse of [`Self::to_bounds`].
+    #[inline]
+    pub fn from_bounds((lower, upper): (Bound<T>, Bound<T>)) -> Self
+    {
+        let lower = match lower {
+            Bound::Included(t) => t,
+            Bound::Excluded(t) => t.just_after(),
+            Bound::Unbounded => -T::INFINITE,
+        };
+        let upper = match upper {
+            Bound::Included(t) => t,
+            Bound::Excluded(t) => t.just_before(),
+            Bound::Unbounded => T::INFINITE,
+        };
+        Self::new(lower, upper)
+    }
+
+    /// Checks if an interval is included in this one
+    ///
+    /// The bounds could be equal so any interval contains itself.
+    /// The empty interval is contained in any interval.
+    #[inline]
+    pub fn contains_interval<TW: TimeConvex<TimePoint=T>>(&self, other: &TW) -> bool
+    {
+        other.is_empty()
+            || (self.lower <= other.lower_bound() && other.upper_bound() <= self.upper)
+    }
+
+    /// Checks if an interval is strictly included in this one
+    ///
+    /// Both bounds of `other` should be strictly inside this interval.
+    /// Infinite bounds are compared as other bounds: so `]-oo,+oo[` strictly
+    /// contains `[a,b]` but not `]-oo,b]`.
+    /// The empty interval is strictly contained in any non-empty interval.
+    #[inline]
+    pub fn contains_interval_strict<TW: TimeConvex<TimePoint=T>>(&self, other: &TW) -> bool
+    {
+        if other.is_empty() {
+            !self.is_empty()
+        } else {
+            self.lower < other.lower_bound() && other.upper_bound() < self.upper
+        }
+    }
+
+    /// Checks if an interval touches this one, without overlapping it
+    ///
+    /// Since time is discrete, the intervals are adjacent if one starts
+    /// just after the end of the other (on either side).
+    /// The empty interval is adjacent to no interval.
+    #[inline]
+    pub fn is_adjacent<TW: TimeConvex<TimePoint=T>>(&self, other: &TW) -> bool
+    {
+        !self.is_empty() && !other.is_empty()
+            && ((self.upper.is_finite() && self.upper.just_after() == other.lower_bound())
+                || (other.upper_bound().is_finite() && other.upper_bound().just_after() == self.lower))
+    }
+
+    /// Merges an interval with this one only if they overlap or touch
+    ///
+    /// Returns the union, which is then an interval, if there is no gap between
+    /// both intervals (see [`Self::is_adjacent`]) and `None` otherwise. Unlike `|`,
+    /// this never produces a time set with several parts.
+    /// The union with an empty interval is the other interval.
+    #[inline]
+    pub fn try_merge<TW: TimeConvex<TimePoint=T>>(&self, other: &TW) -> Option<TimeInterval<T>>
+    {
+        let other = TimeInterval { lower: other.lower_bound(), upper: other.upper_bound() };
+        if other.is_empty() {
+            Some(*self)
+        } else if self.is_empty() {
+            Some(other)
+        } else if (self.lower <= other.upper && other.lower <= self.upper) || self.is_adjacent(&other) {
+            Some(TimeInterval { lower: self.lower.min(other.lower), upper: self.upper.max(other.upper) })
+        } else {
+            None
+        }
+    }
+
+    /// Clamps a point into this interval
+    ///
+    /// Returns the lower bound if the point is before the interval, the upper
+    /// bound if it is after it, and the point itself otherwise. So, for a [`TimeSlot`],
+    /// an out-of-range timestamp is moved to the nearest boundary of the slot.
+    ///
+    /// An unbounded side does not clamp anything. For an empty interval,
+    /// the point is returned unchanged.
+    #[inline]
+    pub fn clamp_point(&self, t: T) -> T
+    {
+        if self.is_empty() {
+            t
+        } else if t < self.lower {
+            self.lower
+        } else if t > self.upper {
+            self.upper
+        } else {
+            t
+        }
+    }
+
+    /// Widens this interval minimally to contain a point
+    ///
+    /// The lower bound becomes `min(lower,t)` and the upper bound `max(upper,t)`,
+    /// so nothing changes if the point is already inside. An empty interval
+    /// becomes the singleton `{t}`: this is the accumulator of the convex envelope
+    /// of a stream of points.
+    #[inline]
+    pub fn extend_to_include(&mut self, t: T)
+    {
+        self.lower = self.lower.min(t);
+        self.upper = self.upper.max(t);
+    }
+
+    /// The smallest interval which contains this one and a point
+    ///
+    /// This is the owned version of [`Self::extend_to_include`].
+    #[inline]
+    pub fn extended_to_include(mut self, t: T) -> Self
+    {
+        self.extend_to_include(t);
+        self
+    }
 }
 
 
@@ -174,6 +330,182 @@ impl<T:TimePoint> TimeInterval<T>
         (lower <= upper).then_some(Self { lower, upper } )
     }
 
+    /// Shrinks the interval on both sides (the inverse of [`Self::enlarge`])
+    ///
+    /// The lower bound moves up by `delta` and the upper bound moves down by `delta`.
+    /// Infinite bounds remain infinite. If the interval becomes empty,
+    /// `None` is returned.
+    #[inline]
+    pub fn shrink(&self, delta: TimeValue) -> Option<Self>
+    {
+        let lower = self.lower + delta;
+        let upper = self.upper - delta;
+        (lower <= upper).then_some(Self { lower, upper } )
+    }
+
+}
+
+impl<T:TimePoint> TimeInterval<T>
+    where T: Sub<Output=TimeValue>
+{
+    /// The duration of the intersection with another interval
+    ///
+    /// The duration is measured as for [`TimeSlot::duration`] (the difference
+    /// between the bounds) and it is zero if the intervals are disjoint.
+    /// If the intersection is unbounded, `TimeValue::INFINITE` is returned.
+    #[inline]
+    pub fn overlap_duration<TW: TimeConvex<TimePoint=T>>(&self, other: &TW) -> TimeValue
+    {
+        let lower = self.lower.max(other.lower_bound());
+        let upper = self.upper.min(other.upper_bound());
+        if upper < lower {
+            TimeValue::default()
+        } else if !lower.is_finite() || !upper.is_finite() {
+            TimeValue::INFINITE
+        } else {
+            upper - lower
+        }
+    }
+
+    /// The probability that a point of this interval is before a point of another one
+    ///
+    /// Each interval is seen as the uncertain occurrence of an event: the time
+    /// points `a` of this interval and `b` of `other` are supposed to be independent
+    /// and uniformly distributed (over continuous time). The probability of `a < b`
+    /// is then computed from the bounds, converted to float seconds.
+    ///
+    /// A singleton is a certain occurrence, so if both intervals are singletons,
+    /// the probability is either 0 or 1. This is also the case if one interval
+    /// is entirely before the other one, even if they are unbounded. Otherwise,
+    /// an unbounded interval has no uniform distribution and `NaN` is returned
+    /// (as for an empty interval).
+    pub fn overlap_probability<TW: TimeConvex<TimePoint=T>>(&self, other: &TW) -> f64
+    {
+        let (lower, upper) = (other.lower_bound(), other.upper_bound());
+        if self.is_empty() || other.is_empty() {
+            return f64::NAN;
+        } else if self.upper < lower {
+            return 1.;
+        } else if upper <= self.lower {
+            return 0.;
+        } else if [self.lower, self.upper, lower, upper].iter().any(|t| !t.is_finite()) {
+            return f64::NAN;
+        }
+        // all the bounds are taken relatively to the lower bound of this interval
+        let secs = |t: T| (t - self.lower).as_secs_f64();
+        let (a, b1, b2) = (secs(self.upper), secs(lower), secs(upper));
+        if a == 0. {
+            // the singleton is inside [b1,b2] (but not at its upper bound)
+            b2 / (b2 - b1)
+        } else if b1 == b2 {
+            // the singleton is inside ]0,a]
+            b1 / a
+        } else {
+            // for a point x of [0,a], the probability of x < b decreases
+            // linearly from 1 to 0 between b1 and b2
+            let (lo, hi) = (b1.max(0.), b2.min(a));
+            let ramp = if lo < hi { (hi-lo) * (2.*b2 - lo - hi) / (2. * (b2-b1)) } else { 0. };
+            (b1.clamp(0., a) + ramp) / a
+        }
+    }
+
+    /// Checks if the bounds of two intervals are equal within a tolerance
+    ///
+    /// This is useful to compare intervals computed with rounding (e.g. after
+    /// a scaling by a float). The infinite bounds should be exactly equal and
+    /// an empty interval is only approximately equal to another empty one.
+    #[inline]
+    pub fn approx_eq(&self, other: &Self, tolerance: TimeValue) -> bool
+    {
+        if self.is_empty() || other.is_empty() {
+            self.is_empty() && other.is_empty()
+        } else {
+            approx_eq_bounds(self.lower, other.lower, tolerance)
+                && approx_eq_bounds(self.upper, other.upper, tolerance)
+        }
+    }
+
+    /// Splits a bounded interval into contiguous sub-intervals of near-equal lengths
+    ///
+    /// The returned sub-intervals are sorted, disjoint and their union is exactly
+    /// this interval (each one starts just after the end of the previous one).
+    /// Their numbers of ticks differ by at most one, the longest ones coming first.
+    /// If this interval has less than `n` ticks, each sub-interval is a singleton.
+    ///
+    /// An empty vector is returned if `n` is zero or if the interval is empty or unbounded.
+    pub fn split_into(&self, n: usize) -> Vec<TimeInterval<T>>
+        where T: Add<TimeValue,Output=T>
+    {
+        if n == 0 || self.is_empty() || !self.lower.is_finite() || !self.upper.is_finite() {
+            return vec![];
+        }
+        // number of ticks (i.e. of time points) of the interval
+        let ticks = (self.upper - self.lower).as_ticks() as i128 + 1;
+        let n = (n as i128).min(ticks);
+        let (len, remainder) = (ticks / n, ticks % n);
+        let mut lower = self.lower;
+        (0..n)
+            .map(|k| {
+                let upper = lower + TimeValue::from_ticks((len + (k < remainder) as i128 - 1) as i64);
+                let part = TimeInterval { lower, upper };
+                lower = upper.just_after();
+                part
+            })
+            .collect()
+    }
+
+    /// Snaps the bounds outwards to a grid
+    ///
+    /// The lower bound is floored and the upper bound is ceiled to multiples
+    /// of `grid` (counted from the origin, i.e. the default time point), so the
+    /// quantized interval contains this one. The infinite bounds are unchanged
+    /// and an empty interval remains empty.
+    ///
+    /// # Panics
+    /// Panics if the grid is not strictly positive.
+    pub fn quantize(&self, grid: TimeValue) -> TimeInterval<T>
+        where T: Default + Add<TimeValue,Output=T>
+    {
+        self.snap(grid, TimeValue::floor, TimeValue::ceil)
+    }
+
+    /// Snaps the bounds inwards to a grid
+    ///
+    /// This is the inner variant of [`Self::quantize`]: the lower bound is ceiled
+    /// and the upper bound is floored, so the quantized interval is contained in
+    /// this one. It is empty if no grid point lies in this interval.
+    ///
+    /// # Panics
+    /// Panics if the grid is not strictly positive.
+    pub fn quantize_inner(&self, grid: TimeValue) -> TimeInterval<T>
+        where T: Default + Add<TimeValue,Output=T>
+    {
+        self.snap(grid, TimeValue::ceil, TimeValue::floor)
+    }
+
+    fn snap(&self, grid: TimeValue, lower: fn(TimeValue,TimeValue) -> TimeValue, upper: fn(TimeValue,TimeValue) -> TimeValue) -> TimeInterval<T>
+        where T: Default + Add<TimeValue,Output=T>
+    {
+        assert!( grid.is_strictly_positive() && grid.is_finite(), "the grid should be strictly positive" );
+        if self.is_empty() {
+            return Self::empty();
+        }
+        let snap = |t: T, f: fn(TimeValue,TimeValue) -> TimeValue| {
+            if t.is_finite() { T::default() + f(t - T::default(), grid) } else { t }
+        };
+        TimeInterval::new(snap(self.lower, lower), snap(self.upper, upper))
+    }
+}
+
+#[inline]
+fn approx_eq_bounds<T>(a: T, b: T, tolerance: TimeValue) -> bool
+    where T: TimePoint + Sub<Output=TimeValue>
+{
+    if a.is_finite() && b.is_finite() {
+        a.max(b) - a.min(b) <= tolerance
+    } else {
+        a == b
+    }
 }
 
 
@@ -207,3 +539,316 @@ impl<T:TimePoint> Neg for TimeInterval<T>
 impl<T:TimePoint> From<T> for TimeInterval<T> {
     #[inline] fn from(t: T) -> Self { TimeInterval::singleton(t) }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::testing::h;
+
+    #[test]
+    fn contains_interval()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(1), t(10));
+
+        // equal intervals
+        assert!( a.contains_interval(&a));
+        assert!(!a.contains_interval_strict(&a));
+
+        // proper sub-intervals
+        assert!( a.contains_interval(&TimeSpan::new(t(2), t(9))));
+        assert!( a.contains_interval_strict(&TimeSpan::new(t(2), t(9))));
+        assert!( a.contains_interval(&TimeSpan::new(t(1), t(9))));
+        assert!(!a.contains_interval_strict(&TimeSpan::new(t(1), t(9))));
+        assert!(!a.contains_interval(&TimeSpan::new(t(0), t(9))));
+
+        // infinite bounds
+        assert!( TimeSpan::all().contains_interval_strict(&a));
+        assert!( TimeSpan::all().contains_interval(&TimeSpan::before(t(5))));
+        assert!(!TimeSpan::all().contains_interval_strict(&TimeSpan::before(t(5))));
+        assert!(!a.contains_interval(&TimeSpan::after(t(5))));
+
+        // empty intervals
+        assert!( a.contains_interval_strict(&TimeSpan::empty()));
+        assert!(!TimeSpan::empty().contains_interval(&a));
+        assert!(!TimeSpan::empty().contains_interval_strict(&TimeSpan::empty()));
+    }
+
+    #[test]
+    fn approx_eq()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(0), t(10));
+        assert!( a.approx_eq(&a, t(0)));
+        assert!( a.approx_eq(&TimeSpan::new(t(-1), t(11)), t(1)));
+        assert!( !a.approx_eq(&TimeSpan::new(t(-1), t(12)), t(1)));
+        assert!( !a.approx_eq(&TimeSpan::after(t(0)), TimeValue::from_days(1000)));
+        assert!( TimeSpan::after(t(0)).approx_eq(&TimeSpan::after(t(1)), t(1)));
+        assert!( TimeSpan::empty().approx_eq(&TimeSpan::empty(), t(0)));
+        assert!( !TimeSpan::empty().approx_eq(&a, t(100)));
+        assert!( !a.approx_eq(&TimeSpan::empty(), t(100)));
+
+        let slot = TimeSlot::new(h(1), h(2));
+        assert!( slot.approx_eq(&(slot + t(3)), t(3)));
+        assert!( !slot.approx_eq(&(slot + t(3)), t(2)));
+    }
+
+    #[test]
+    fn is_adjacent()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(0), t(10));
+        assert!( a.is_adjacent(&TimeSpan::new(t(11), t(20))));
+        assert!( TimeSpan::new(t(11), t(20)).is_adjacent(&a));
+        assert!( a.is_adjacent(&t(-1)));
+        assert!( a.is_adjacent(&TimeSpan::before(t(-1))));
+        assert!( a.is_adjacent(&(t(11)..)));
+
+        assert!( !a.is_adjacent(&TimeSpan::new(t(10), t(20))));
+        assert!( !a.is_adjacent(&TimeSpan::new(t(12), t(20))));
+        assert!( !a.is_adjacent(&a));
+        assert!( !a.is_adjacent(&TimeSpan::empty()));
+        assert!( !TimeSpan::empty().is_adjacent(&a));
+        assert!( !TimeSpan::after(t(0)).is_adjacent(&TimeSpan::before(t(-5))));
+        assert!( !TimeSpan::all().is_adjacent(&TimeSpan::all()));
+    }
+
+    #[test]
+    fn try_merge()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(0), t(10));
+        assert_eq!( a.try_merge(&TimeSpan::new(t(11), t(20))), Some(TimeSpan::new(t(0), t(20))));
+        assert_eq!( a.try_merge(&TimeSpan::new(t(5), t(20))), Some(TimeSpan::new(t(0), t(20))));
+        assert_eq!( a.try_merge(&TimeSpan::new(t(2), t(3))), Some(a));
+        assert_eq!( a.try_merge(&t(-1)), Some(TimeSpan::new(t(-1), t(10))));
+        assert_eq!( a.try_merge(&(t(11)..)), Some(TimeSpan::after(t(0))));
+        assert_eq!( a.try_merge(&TimeSpan::all()), Some(TimeSpan::all()));
+        assert_eq!( a.try_merge(&TimeSpan::empty()), Some(a));
+        assert_eq!( TimeSpan::empty().try_merge(&a), Some(a));
+
+        assert_eq!( a.try_merge(&TimeSpan::new(t(12), t(20))), None);
+        assert_eq!( TimeSpan::new(t(12), t(20)).try_merge(&a), None);
+        assert_eq!( TimeSpan::after(t(0)).try_merge(&TimeSpan::before(t(-2))), None);
+        assert!( !(a | TimeSpan::new(t(12), t(20))).is_convex());
+    }
+
+    #[test]
+    fn overlap_probability()
+    {
+        let t = TimeValue::from_secs;
+        let a = TimeSpan::new(t(0), t(10));
+        assert_eq!( a.overlap_probability(&a), 0.5);
+        assert_eq!( a.overlap_probability(&TimeSpan::new(t(5), t(15))), 0.875);
+        assert_eq!( TimeSpan::new(t(5), t(15)).overlap_probability(&a), 0.125);
+        assert_eq!( a.overlap_probability(&TimeSpan::new(t(2), t(4))), 0.3);
+        assert_eq!( a.overlap_probability(&TimeSpan::new(t(20), t(30))), 1.);
+        assert_eq!( a.overlap_probability(&TimeSpan::new(t(-20), t(-10))), 0.);
+
+        // singletons
+        assert_eq!( a.overlap_probability(&t(4)), 0.4);
+        assert_eq!( TimeSpan::singleton(t(4)).overlap_probability(&a), 0.6);
+        assert_eq!( TimeSpan::singleton(t(4)).overlap_probability(&t(5)), 1.);
+        assert_eq!( TimeSpan::singleton(t(4)).overlap_probability(&t(4)), 0.);
+        assert_eq!( a.overlap_probability(&t(10)), 1.);
+        assert_eq!( a.overlap_probability(&t(0)), 0.);
+
+        // unbounded intervals
+        assert_eq!( TimeSpan::before(t(0)).overlap_probability(&TimeSpan::after(t(1))), 1.);
+        assert!( TimeSpan::after(t(0)).overlap_probability(&a).is_nan());
+        assert!( TimeSpan::empty().overlap_probability(&a).is_nan());
+
+        assert_eq!( TimeSlot::new(h(8), h(10)).overlap_probability(&TimeSlot::new(h(9), h(11))), 0.875);
+    }
+
+    #[test]
+    fn extend_to_include()
+    {
+        let t = TimeValue::from_ticks;
+        let mut a = TimeSpan::new(t(0), t(10));
+        a.extend_to_include(t(5));
+        assert_eq!( a, TimeSpan::new(t(0), t(10)));
+        a.extend_to_include(t(15));
+        assert_eq!( a, TimeSpan::new(t(0), t(15)));
+        a.extend_to_include(-t(3));
+        assert_eq!( a, TimeSpan::new(-t(3), t(15)));
+        assert_eq!( TimeSpan::after(t(0)).extended_to_include(t(-2)), TimeSpan::after(t(-2)));
+
+        assert_eq!( TimeSpan::empty().extended_to_include(t(4)), TimeSpan::singleton(t(4)));
+        let envelope = [t(7), t(2), t(9), t(4)].into_iter()
+            .fold(TimeSpan::empty(), TimeSpan::extended_to_include);
+        assert_eq!( envelope, TimeSpan::new(t(2), t(9)));
+    }
+
+    #[test]
+    fn clamp_point()
+    {
+        let morning = TimeSlot::new(h(8), h(12));
+        assert_eq!( morning.clamp_point(h(10)), h(10));
+        assert_eq!( morning.clamp_point(h(6)), h(8));
+        assert_eq!( morning.clamp_point(h(14)), h(12));
+        assert_eq!( morning.clamp_point(Timestamp::INFINITE), h(12));
+        assert_eq!( TimeSlot::after(h(8)).clamp_point(h(14)), h(14));
+        assert_eq!( TimeSlot::before(h(8)).clamp_point(h(4)), h(4));
+        assert_eq!( TimeSlot::before(h(8)).clamp_point(h(14)), h(8));
+        assert_eq!( TimeSlot::all().clamp_point(h(14)), h(14));
+        assert_eq!( TimeSlot::empty().clamp_point(h(14)), h(14));
+    }
+
+    #[test]
+    fn overlap_duration()
+    {
+        let morning = TimeSlot::new(h(8), h(12));
+        assert_eq!( morning.overlap_duration(&TimeSlot::new(h(10), h(14))), TimeValue::from_hours(2));
+        assert_eq!( morning.overlap_duration(&TimeSlot::new(h(9), h(10))), TimeValue::from_hours(1));
+        assert_eq!( morning.overlap_duration(&h(9)), TimeValue::default());
+        assert_eq!( morning.overlap_duration(&TimeSlot::new(h(13), h(14))), TimeValue::default());
+        assert_eq!( morning.overlap_duration(&TimeSlot::empty()), TimeValue::default());
+        assert_eq!( morning.overlap_duration(&TimeSlot::after(h(10))), TimeValue::from_hours(2));
+        assert_eq!( TimeSlot::before(h(10)).overlap_duration(&TimeSlot::all()), TimeValue::INFINITE);
+
+        let t = TimeValue::from_ticks;
+        assert_eq!( TimeSpan::new(t(-5), t(5)).overlap_duration(&TimeSpan::new(t(0), t(10))), t(5));
+        assert_eq!( TimeSpan::new(t(-5), t(5)).overlap_duration(&(t(-10)..=t(-5))), t(0));
+    }
+
+    #[test]
+    fn split_into()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(0), t(9));
+        assert_eq!( a.split_into(2), vec![TimeSpan::new(t(0), t(4)), TimeSpan::new(t(5), t(9))]);
+        assert_eq!( a.split_into(3), vec![TimeSpan::new(t(0), t(3)), TimeSpan::new(t(4), t(6)), TimeSpan::new(t(7), t(9))]);
+        assert_eq!( a.split_into(1), vec![a]);
+        assert_eq!( a.split_into(20).len(), 10);
+        assert!( a.split_into(20).iter().all(|p| p.is_singleton()));
+        assert_eq!( a.split_into(7).into_iter().collect::<TimeSpans>(), a);
+
+        let day = TimeSlot::new(h(0), h(24).just_before());
+        let hours = day.split_into(24);
+        assert_eq!( hours.len(), 24);
+        assert_eq!( hours[5], TimeSlot::new(h(5), h(6).just_before()));
+
+        assert!( a.split_into(0).is_empty());
+        assert!( TimeSpan::empty().split_into(2).is_empty());
+        assert!( TimeSpan::after(t(0)).split_into(2).is_empty());
+        assert!( TimeSpan::all().split_into(2).is_empty());
+    }
+
+    #[test]
+    fn quantize()
+    {
+        let m = |n| Timestamp::from_origin(TimeValue::from_mins(n));
+        let grid = TimeValue::from_mins(15);
+        let slot = TimeSlot::new(m(8*60+5), m(9*60+20));
+        assert_eq!( slot.quantize(grid), TimeSlot::new(m(8*60), m(9*60+30)));
+        assert_eq!( slot.quantize_inner(grid), TimeSlot::new(m(8*60+15), m(9*60+15)));
+        assert!( slot.quantize(grid).contains_interval(&slot));
+        assert!( slot.contains_interval(&slot.quantize_inner(grid)));
+
+        // already aligned
+        let aligned = TimeSlot::new(m(8*60), m(9*60));
+        assert_eq!( aligned.quantize(grid), aligned);
+        assert_eq!( aligned.quantize_inner(grid), aligned);
+
+        // no grid point inside
+        assert!( TimeSlot::new(m(1), m(14)).quantize_inner(grid).is_empty());
+        assert_eq!( TimeSlot::new(m(1), m(14)).quantize(grid), TimeSlot::new(m(0), m(15)));
+
+        let t = TimeValue::from_mins;
+        assert_eq!( TimeSpan::new(-t(20), -t(5)).quantize(grid), TimeSpan::new(-t(30), t(0)));
+        assert_eq!( TimeSpan::new(-t(20), -t(5)).quantize_inner(grid), TimeSpan::singleton(-t(15)));
+        assert_eq!( TimeSpan::after(t(7)).quantize(grid), TimeSpan::after(t(0)));
+        assert_eq!( TimeSpan::before(t(7)).quantize_inner(grid), TimeSpan::before(t(0)));
+        assert_eq!( TimeSpan::all().quantize(grid), TimeSpan::all());
+        assert!( TimeSpan::empty().quantize(grid).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn quantize_null_grid()
+    {
+        let _ = TimeSpan::new(TimeValue::from_mins(1), TimeValue::from_mins(2)).quantize(TimeValue::default());
+    }
+
+    #[test]
+    fn shrink()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(1), t(10));
+
+        assert_eq!( a.shrink(t(2)), Some(TimeSpan::new(t(3), t(8))) );
+        assert_eq!( a.shrink(t(2)).and_then(|b| b.enlarge(t(2))), Some(a) );
+        assert_eq!( a.shrink(t(0)), Some(a) );
+
+        // at the collapse boundary
+        let b = TimeSpan::new(t(1), t(11));
+        assert_eq!( b.shrink(t(5)), Some(TimeSpan::singleton(t(6))) );
+        assert_eq!( b.shrink(t(6)), None );
+        assert_eq!( a.shrink(t(4)), Some(TimeSpan::new(t(5), t(6))) );
+        assert_eq!( a.shrink(t(5)), None );
+
+        // infinite bounds are not moved
+        assert_eq!( TimeSpan::after(t(1)).shrink(t(2)), Some(TimeSpan::after(t(3))) );
+        assert_eq!( TimeSpan::before(t(1)).shrink(t(2)), Some(TimeSpan::before(t(-1))) );
+        assert_eq!( TimeSpan::all().shrink(t(2)), Some(TimeSpan::all()) );
+        assert_eq!( TimeSpan::empty().shrink(t(2)), None );
+    }
+
+    #[test]
+    fn checked_bounds()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(1), t(10));
+        assert_eq!( a.checked_lower_bound(), Some(t(1)) );
+        assert_eq!( a.checked_upper_bound(), Some(t(10)) );
+        assert_eq!( TimeSpan::empty().checked_lower_bound(), None );
+        assert_eq!( TimeSpan::empty().checked_upper_bound(), None );
+        assert_eq!( TimeSpans::empty().checked_lower_bound(), None );
+        assert_eq!( TimeSpans::all().checked_upper_bound(), Some(TimeValue::INFINITE) );
+
+        assert_eq!( TimeSpan::empty().format_timeset(""), "{}" );
+        assert_eq!( TimeSpans::empty().format_timeset(""), "{}" );
+        assert_eq!( TimeSpan::singleton(t(0)).format_timeset(""), "{0}" );
+        assert_eq!( TimeSpan::before(t(0)).format_timeset(""), "]-oo,0]" );
+        assert_eq!( TimeSpan::all().format_timeset(""), "]-oo,+oo[" );
+    }
+
+    #[test]
+    fn bounds()
+    {
+        use core::ops::{Bound::*, RangeBounds};
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(1), t(10));
+        assert_eq!( a.to_bounds(), (Included(t(1)), Included(t(10))) );
+        assert_eq!( TimeSpan::after(t(1)).to_bounds(), (Included(t(1)), Unbounded) );
+        assert_eq!( TimeSpan::all().to_bounds(), (Unbounded, Unbounded) );
+        assert!( !TimeSpan::empty().to_bounds().contains(&t(0)) );
+
+        for tw in [a, TimeSpan::singleton(t(3)), TimeSpan::before(t(1)), TimeSpan::all(), TimeSpan::empty()] {
+            assert_eq!( TimeSpan::from_bounds(tw.to_bounds()), tw );
+        }
+        assert_eq!( TimeSpan::from_bounds((Excluded(t(1)), Excluded(t(10)))), TimeSpan::new(t(2), t(9)) );
+        assert_eq!( TimeSpan::from_bounds((Excluded(t(1)), Excluded(t(2)))), TimeSpan::empty() );
+        assert_eq!( TimeSpan::from_bounds((Unbounded, Excluded(t(2)))), TimeSpan::before(t(1)) );
+
+        let events = (0..24).map(|n| (h(n), n)).collect::<std::collections::BTreeMap<_,_>>();
+        let slot = TimeSlot::new(h(8), h(11));
+        assert_eq!( events.range(slot.to_bounds()).map(|(_, n)| *n).collect::<Vec<_>>(), vec![8, 9, 10, 11] );
+        assert_eq!( events.range(TimeSlot::after(h(22)).to_bounds()).count(), 2 );
+    }
+
+    #[test]
+    fn as_range()
+    {
+        let t = TimeValue::from_ticks;
+        let a = TimeSpan::new(t(1), t(10));
+        assert_eq!( a.as_range(), Some(t(1)..=t(10)) );
+        assert_eq!( a.as_range().map(TimeSpan::from), Some(a) );
+        assert_eq!( TimeSpan::singleton(t(3)).as_range(), Some(t(3)..=t(3)) );
+        assert_eq!( TimeSpan::after(t(1)).as_range(), None );
+        assert_eq!( TimeSpan::before(t(1)).as_range(), None );
+        assert_eq!( TimeSpan::empty().as_range(), None );
+    }
+}
@@ -1,5 +1,6 @@
-use std::ops::Neg;
+use core::ops::{Add, Neg, Sub};
 use crate::*;
+use crate::iter::{TimeComplementary, TimeConvexIterator};
 
 
 /// A union of [`TimeSpan`] (aliased to [`TimeSet<TimeValue>`])
@@ -50,8 +51,457 @@ impl<T:TimePoint> TimeSet<T>
     #[inline]
     pub fn empty() -> Self { Self(vec![]) }
 
+    /// A set built from `(lower, upper)` pairs given in any order
+    ///
+    /// Each pair defines an interval `[lower,upper]` and the overlapping or
+    /// touching intervals are merged (as when collecting intervals).
+    /// An error [`TimeError::EmptyInterval`] is returned if a lower bound
+    /// is greater than its upper bound.
+    pub fn from_pairs(pairs: &[(T,T)]) -> TimeResult<Self>
+    {
+        pairs.iter()
+            .map(|&(lower, upper)| {
+                if lower <= upper { Ok(TimeInterval { lower, upper }) } else { Err(TimeError::EmptyInterval) }
+            })
+            .collect()
+    }
+
+    /// A set of singletons built from points given in any order
+    ///
+    /// The duplicate points are merged, as well as the consecutive ones
+    /// (i.e. separated by one tick) which then form an interval.
+    #[inline]
+    pub fn from_points(points: &[T]) -> Self
+    {
+        points.iter().copied().map(TimeInterval::singleton).collect()
+    }
+
+    /// An empty set with room for `n` convex parts
+    ///
+    /// Useful to avoid reallocations when a set is built part by part.
+    #[inline]
+    pub fn with_capacity(n: usize) -> Self { Self(Vec::with_capacity(n)) }
+
+    /// The number of convex parts this set can hold without reallocating
+    #[inline]
+    pub fn capacity(&self) -> usize { self.0.capacity() }
+
+    /// Reserves room for at least `additional` more convex parts
+    #[inline]
+    pub fn reserve(&mut self, additional: usize) { self.0.reserve(additional) }
+
+    /// Shrinks the capacity as much as possible
     #[inline]
     pub fn shrink_to_fit(&mut self) { self.0.shrink_to_fit() }
+
+    /// Applies a monotonic transform to all the bounds
+    ///
+    /// The function `f` should be monotonic non-decreasing (i.e. `a <= b`
+    /// implies `f(a) <= f(b)`) so the intervals remain sorted, but they could
+    /// overlap or touch each other and, in this case, they are merged.
+    ///
+    /// The infinite bounds are not transformed and remain infinite.
+    pub fn map_points<F: Fn(T) -> T>(&self, f: F) -> TimeSet<T>
+    {
+        let map = |t:T| if t.is_finite() { f(t) } else { t };
+        self.0.iter()
+            .map(|tw| TimeInterval { lower: map(tw.lower), upper: map(tw.upper) })
+            .collect()
+    }
+
+    /// Applies an arbitrary transform to each convex part
+    ///
+    /// Unlike [`Self::map_points`], the transform could reorder the parts
+    /// or make them overlap: the result is sorted again and the overlapping
+    /// or touching parts are merged (the empty ones are dropped), so it
+    /// is always a valid time set. This costs `O(n log n)` if the parts
+    /// are no more sorted (and `O(n)` otherwise).
+    pub fn map_intervals<F: FnMut(TimeInterval<T>) -> TimeInterval<T>>(&self, f: F) -> TimeSet<T>
+    {
+        self.0.iter().copied().map(f).collect()
+    }
+
+    /// Iterates over the gaps between the convex parts
+    ///
+    /// The gaps are the parts of the complementary of this set which are
+    /// between its lower and upper bounds: unlike the complementary, the
+    /// unbounded parts before and after the set are not produced.
+    /// For instance, the gaps of `[1,5]U[10,20]` are `[6,9]`.
+    #[inline]
+    pub fn iter_gaps(&self) -> impl TimeConvexIterator<TimePoint=T>
+    {
+        self.iter().gaps_within(self.convex_envelope())
+    }
+
+    /// Checks if all the points of `other` are in this set
+    ///
+    /// Each part of `other` should be included in one part of `self`.
+    /// Since both are sorted, this is checked in one pass over the two sets,
+    /// without computing the difference.
+    ///
+    /// An empty set is covered by any set.
+    pub fn covers(&self, other: &TimeSet<T>) -> bool
+    {
+        let mut parts = self.0.iter().peekable();
+        other.0.iter().all(|tw| {
+            while parts.next_if(|ts| ts.upper < tw.lower).is_some() { }
+            parts.peek().is_some_and(|ts| ts.lower <= tw.lower && tw.upper <= ts.upper)
+        })
+    }
+
+    /// Checks if this set and `other` have no point in common
+    ///
+    /// Both sets are walked in one pass, which stops as soon as two parts
+    /// overlap, without computing the intersection.
+    ///
+    /// An empty set is disjoint from any set (even from itself).
+    pub fn is_disjoint(&self, other: &TimeSet<T>) -> bool
+    {
+        let (mut a, mut b) = (self.0.iter(), other.0.iter());
+        let (mut x, mut y) = (a.next(), b.next());
+        while let (Some(tx), Some(ty)) = (x, y) {
+            if tx.upper < ty.lower {
+                x = a.next();
+            } else if ty.upper < tx.lower {
+                y = b.next();
+            } else {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Checks if all the given time points are in this set
+    ///
+    /// If the points are sorted, they are checked in one pass over the parts of
+    /// the set. Otherwise, each point is searched by dichotomy. In both cases,
+    /// this is more efficient than checking the points one by one with `contains`.
+    ///
+    /// Returns `true` if there is no point.
+    #[inline]
+    pub fn contains_all(&self, points: &[T]) -> bool
+    {
+        self.contained(points).all(|b| b)
+    }
+
+    /// Checks which of the given time points are in this set
+    ///
+    /// The `k`-th boolean tells if the `k`-th point is in the set
+    /// (see [`Self::contains_all`] for the complexity).
+    #[inline]
+    pub fn which_contained(&self, points: &[T]) -> Vec<bool>
+    {
+        self.contained(points).collect()
+    }
+
+    fn contained<'a>(&'a self, points: &'a [T]) -> impl Iterator<Item=bool> + 'a
+    {
+        let sorted = points.is_sorted();
+        let mut parts = self.0.iter().peekable();
+        points.iter().map(move |&t| {
+            if sorted {
+                while parts.next_if(|tw| tw.upper < t).is_some() { }
+                parts.peek().is_some_and(|tw| tw.lower <= t)
+            } else {
+                let k = self.0.partition_point(|tw| tw.upper < t);
+                self.0.get(k).is_some_and(|tw| tw.lower <= t)
+            }
+        })
+    }
+
+    /// Intersects several sets at once
+    ///
+    /// The sorted parts of all the sets are merged in one pass, so no
+    /// intermediate set is built (as it is when chaining `&`).
+    ///
+    /// The intersection of no set is the whole time set.
+    pub fn intersection_many(sets: &[&TimeSet<T>]) -> TimeSet<T>
+    {
+        if sets.is_empty() {
+            return Self::all();
+        }
+        let mut cursors = vec![0; sets.len()];
+        let mut parts = vec![];
+        loop {
+            let mut lower = -T::INFINITE;
+            let mut first = None; // the set whose current part ends first
+            for (k, set) in sets.iter().enumerate() {
+                let Some(tw) = set.0.get(cursors[k]) else {
+                    return TimeSet(parts);
+                };
+                lower = lower.max(tw.lower);
+                if first.is_none_or(|(_, upper)| tw.upper < upper) {
+                    first = Some((k, tw.upper));
+                }
+            }
+            let (k, upper) = first.unwrap();
+            if lower <= upper {
+                parts.push(TimeInterval { lower, upper });
+            }
+            cursors[k] += 1;
+        }
+    }
+}
+
+impl<T:TimePoint> TimeSet<T>
+    where T: Add<TimeValue,Output=T> + Sub<Output=TimeValue>
+{
+    /// Splits the set into consecutive chunks of bounded duration
+    ///
+    /// The duration of a chunk is the sum of the durations of its parts
+    /// (measured as for [`TimeSlot::duration`]) and it does not exceed `max`.
+    /// A part which does not fit in a chunk is cut and its remainder starts
+    /// the next chunk: so a part longer than `max` is split over several chunks
+    /// of one part each.
+    ///
+    /// # Panics
+    /// Panics if `max` is not strictly positive or if the set is unbounded.
+    pub fn chunks_by_duration(&self, max: TimeValue) -> Vec<TimeSet<T>>
+    {
+        assert!( max.is_strictly_positive(), "the maximal duration should be strictly positive");
+        assert!( self.is_empty() || self.is_bounded(), "an unbounded time set could not be chunked");
+        let mut chunks = Vec::new();
+        let mut chunk = Vec::new();
+        let mut remaining = max;
+        for tw in &self.0 {
+            let mut lower = tw.lower;
+            loop {
+                if remaining.is_zero() && !chunk.is_empty() {
+                    chunks.push(TimeSet(core::mem::take(&mut chunk)));
+                    remaining = max;
+                }
+                if tw.upper - lower <= remaining {
+                    chunk.push(TimeInterval { lower, upper: tw.upper });
+                    remaining -= tw.upper - lower;
+                    break;
+                }
+                let cut = lower + remaining;
+                chunk.push(TimeInterval { lower, upper: cut });
+                chunks.push(TimeSet(core::mem::take(&mut chunk)));
+                remaining = max;
+                lower = cut.just_after();
+            }
+        }
+        if !chunk.is_empty() {
+            chunks.push(TimeSet(chunk));
+        }
+        chunks
+    }
+}
+
+impl<T:TimePoint> TimeSet<T>
+    where T: Sub<Output=TimeValue>
+{
+    /// Measures the impact of removing another set from this one
+    ///
+    /// Returns the number of convex parts of this set which intersect `other`
+    /// (i.e. which are cut or removed by the difference `self - other`) and the
+    /// total duration removed (each intersection being measured as for
+    /// [`TimeSlot::duration`]).
+    ///
+    /// This is computed in one pass over both sets, without building the difference.
+    pub fn difference_metrics(&self, other: &TimeSet<T>) -> (usize, TimeValue)
+    {
+        let (mut a, mut b) = (self.0.iter(), other.0.iter());
+        let (mut x, mut y) = (a.next(), b.next());
+        let (mut count, mut removed) = (0, TimeValue::default());
+        let mut affected = false;
+        while let (Some(tx), Some(ty)) = (x, y) {
+            if tx.upper < ty.lower {
+                x = a.next(); affected = false;
+            } else if ty.upper < tx.lower {
+                y = b.next();
+            } else {
+                if !affected { count += 1; affected = true; }
+                removed += tx.upper.min(ty.upper) - tx.lower.max(ty.lower);
+                if tx.upper <= ty.upper {
+                    x = a.next(); affected = false;
+                } else {
+                    y = b.next();
+                }
+            }
+        }
+        (count, removed)
+    }
+
+    /// Checks if two time sets are equal within a tolerance
+    ///
+    /// Both sets should have the same number of convex parts and each part
+    /// should be approximately equal to the part at the same position in
+    /// the other set (see [`TimeInterval::approx_eq`]).
+    pub fn approx_eq(&self, other: &Self, tolerance: TimeValue) -> bool
+    {
+        self.0.len() == other.0.len()
+            && self.0.iter().zip(&other.0).all(|(a,b)| a.approx_eq(b, tolerance))
+    }
+}
+
+impl TimeSpans
+{
+    /// Builds `count` periodic intervals
+    ///
+    /// The intervals are `[start+k*period, start+k*period+width]` for `k` in `0..count`
+    /// (so `width` is the length of each interval, as for [`TimeSlot::duration`]).
+    /// If the period is exactly one tick longer than the width, the intervals
+    /// are contiguous and they are merged into one interval. The intervals which
+    /// end after the finite range are dropped (so the result is always bounded).
+    ///
+    /// # Panics
+    /// Panics if `period <= width` (the intervals would overlap)
+    /// or if `width` is negative.
+    pub fn periodic(start: TimeValue, period: TimeValue, width: TimeValue, count: usize) -> TimeSpans
+    {
+        assert!( period > width, "the period should be greater than the width" );
+        assert!( width.is_positive(), "the width should be positive" );
+        let nth = |k: usize| start + period.saturating_mul(k as i64);
+        // the intervals which end in the finite range form a prefix of 0..count
+        let (mut finite, mut last) = (0, count);
+        while finite < last {
+            let k = finite + (last-finite)/2;
+            if (nth(k) + width).is_finite() { finite = k+1; } else { last = k; }
+        }
+        if finite == 0 {
+            TimeSet::empty()
+        } else if width.just_after() == period {
+            TimeSet::convex(start, nth(finite-1) + width)
+        } else {
+            TimeSet((0..finite)
+                .map(|k| TimeInterval { lower: nth(k), upper: nth(k) + width })
+                .collect())
+        }
+    }
+
+    /// Distributes the covered durations over regular buckets
+    ///
+    /// The buckets are the intervals `[origin+k*bucket, origin+(k+1)*bucket[` (given
+    /// as closed intervals, so their upper bound is one tick before the next bucket)
+    /// which cover the envelope of this set, in chronological order. Each one comes
+    /// with the duration of the set which falls into it (e.g. the busy minutes per hour).
+    /// The parts are measured as for [`TimeSlot::duration`] and they are cut at the
+    /// bucket boundaries, so the durations of the buckets sum to the one of the set.
+    ///
+    /// Returns an empty vector if the set is empty.
+    ///
+    /// # Panics
+    /// Panics if `bucket` is not strictly positive and finite or if the set is unbounded.
+    pub fn histogram(&self, origin: TimeValue, bucket: TimeValue) -> Vec<(TimeSpan, TimeValue)>
+    {
+        assert!( bucket.is_strictly_positive() && bucket.is_finite(), "the bucket width should be strictly positive and finite");
+        if self.is_empty() { return vec![]; }
+        assert!( self.is_bounded(), "an unbounded time set could not be binned");
+
+        let k = (self.lower_bound() - origin).as_ticks().div_euclid(bucket.as_ticks());
+        let mut start = origin + TimeValue::from_ticks(k * bucket.as_ticks());
+        let mut first = 0;
+        let mut buckets = Vec::new();
+        while start <= self.upper_bound() {
+            let end = start + bucket;
+            let covered = self.0[first..].iter()
+                .take_while(|tw| tw.lower < end)
+                .map(|tw| tw.upper.min(end) - tw.lower.max(start))
+                .filter(|d| d.is_strictly_positive())
+                .sum();
+            // the parts which end in this bucket are no more relevant
+            first += self.0[first..].iter().take_while(|tw| tw.upper < end).count();
+            buckets.push((TimeInterval { lower: start, upper: end.just_before() }, covered));
+            start = end;
+        }
+        buckets
+    }
+
+    /// Converts durations into dates relatively to an origin
+    ///
+    /// This is the same as `self + origin` and
+    /// the inverse of [`TimeSlots::relative_to`].
+    #[inline]
+    pub fn at_origin(&self, origin: Timestamp) -> TimeSlots
+    {
+        self.clone() + origin
+    }
+
+    /// Reflects all the durations around a center
+    ///
+    /// Each interval `[a,b]` becomes `[2c-b,2c-a]` where `c` is the center
+    /// (the infinite bounds are reversed). Reflecting twice around the same
+    /// center gives back the original set.
+    pub fn reflect(&self, center: TimeValue) -> TimeSpans
+    {
+        let reflect = |t: TimeValue| if t.is_finite() { center + (center - t) } else { -t };
+        // reflection reverses the order (but preserves the gaps)
+        TimeSet(self.0.iter().rev()
+            .map(|tw| TimeInterval { lower: reflect(tw.upper), upper: reflect(tw.lower) })
+            .collect())
+    }
+}
+
+impl TimeSlots
+{
+    /// Converts dates into durations relatively to an origin
+    ///
+    /// This is the inverse of [`TimeSpans::at_origin`].
+    #[inline]
+    pub fn relative_to(&self, origin: Timestamp) -> TimeSpans
+    {
+        // translation preserves the order and the gaps between the slots
+        TimeSet(self.0.iter().map(|tw| *tw - origin).collect())
+    }
+
+    /// Finds the earliest free slot of a given duration
+    ///
+    /// This set is considered as the busy slots: the returned slot starts at
+    /// or after `after`, lasts exactly `duration` (measured as for
+    /// [`TimeSlot::duration`]) and does not overlap any busy slot. Notice that
+    /// the slots are closed so a free slot could not start at the end of a busy one
+    /// (but just after it).
+    ///
+    /// Returns `None` if there is no such free slot or if the duration is negative.
+    pub fn next_free(&self, after: Timestamp, duration: TimeValue) -> Option<TimeSlot>
+    {
+        if duration.is_strictly_negative() || !after.is_finite() {
+            return None;
+        }
+        self.iter()
+            .complementary()
+            .bounded_by(TimeSlot::after(after))
+            .find(|free| !free.upper.is_finite() || free.upper - free.lower >= duration)
+            .map(|free| TimeSlot::new(free.lower, free.lower + duration))
+            .filter(|slot| slot.upper.is_finite())
+    }
+
+    /// Reflects all the slots around a central date (see [`TimeSpans::reflect`])
+    pub fn reflect(&self, center: Timestamp) -> TimeSlots
+    {
+        self.relative_to(center).reflect(TimeValue::default()).at_origin(center)
+    }
+
+    /// Shifts all the slots by a number of days
+    ///
+    /// A day is a fixed duration of 24 hours (see [`TimeValue::from_days`]):
+    /// since timestamps are UTC, there is no daylight saving time to deal with.
+    ///
+    /// The infinite bounds remain infinite but an error is returned
+    /// if a finite bound is shifted beyond the finite timestamps.
+    pub fn shift_days(&self, n: i64) -> TimeResult<TimeSlots>
+    {
+        let delta = TimeValue::from_days(n);
+        let overflow = if n < 0 { TimeError::PastOverflow } else { TimeError::FutureOverflow };
+        if !delta.is_finite() {
+            return Err(overflow);
+        }
+        self.0.iter()
+            .map(|tw| {
+                let lower = tw.lower + delta;
+                let upper = tw.upper + delta;
+                if (tw.lower.is_finite() && !lower.is_finite()) || (tw.upper.is_finite() && !upper.is_finite()) {
+                    Err(overflow.clone())
+                } else {
+                    // translation preserves the order and the gaps between the slots
+                    Ok(TimeInterval { lower, upper })
+                }
+            })
+            .collect::<TimeResult<Vec<_>>>()
+            .map(TimeSet)
+    }
 }
 
 
@@ -104,7 +554,7 @@ impl<T:TimePoint> TimeWindow for TimeSet<T>
     #[inline]
     fn convex_count(&self) -> usize { self.0.len() }
 
-    type ConvexIter = crate::iter::intoiter::IntoConvexIter<T,std::vec::IntoIter<TimeInterval<T>>>;
+    type ConvexIter = crate::iter::intoiter::IntoConvexIter<T,alloc::vec::IntoIter<TimeInterval<T>>>;
 
     fn iter(&self) -> Self::ConvexIter {
         crate::iter::intoiter::IntoConvexIter(self.0.clone().into_iter())
@@ -122,19 +572,30 @@ impl<T:TimePoint> Neg for TimeSet<T>
 
 impl<T:TimePoint> FromIterator<TimeInterval<T>> for TimeSet<T>
 {
+    /// Collects intervals given in any order
+    ///
+    /// The intervals are sorted (if needed) and the overlapping or
+    /// touching ones are merged, so the result is always a valid time set.
     fn from_iter<I: IntoIterator<Item=TimeInterval<T>>>(iter: I) -> Self
     {
-        iter.into_iter()
-            .fold(TimeSet::empty(), |mut r,i | {
-                // very most of the time, time iterators are chronologically sorted
-                // if the gap is more than one tick, just add the new convex at the end
-                if i.lower_bound() > r.upper_bound().just_after() {
-                    r.0.push(i)
-                } else {
-                    r |= i
-                }
-                r
-            })
+        let mut parts = iter.into_iter()
+            .filter(|i| !i.is_empty())
+            .collect::<Vec<_>>();
+        // very most of the time, time iterators are chronologically sorted
+        if !parts.windows(2).all(|w| w[0].lower <= w[1].lower) {
+            parts.sort_unstable_by_key(|i| i.lower);
+        }
+        // if the gap is more than one tick, just keep the new convex
+        // and if it overlaps (or touches) the previous one, just extend it
+        parts.dedup_by(|i, last| {
+            if i.lower > last.upper.just_after() {
+                false
+            } else {
+                if i.upper > last.upper { last.upper = i.upper; }
+                true
+            }
+        });
+        TimeSet(parts)
     }
 }
 
@@ -209,3 +670,397 @@ impl<T:TimePoint> TimeTruncation for TimeSet<T>
     }
 }
 
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::testing::h;
+
+    #[test]
+    fn shift_days()
+    {
+        let day = |d| Timestamp::from_origin(TimeValue::from_days(d));
+        let week = TimeSlot::new(day(0), day(1)) | TimeSlot::new(day(3), day(4));
+        assert_eq!(
+            week.shift_days(7),
+            Ok(TimeSlot::new(day(7), day(8)) | TimeSlot::new(day(10), day(11)))
+        );
+        assert_eq!(week.shift_days(7).and_then(|w| w.shift_days(-7)), Ok(week.clone()));
+
+        let after = TimeSlots::from(TimeSlot::after(day(0)));
+        assert_eq!(after.shift_days(7), Ok(TimeSlot::after(day(7)).into()));
+        assert_eq!(week.shift_days(1_000_000), Err(TimeError::FutureOverflow));
+        assert_eq!(week.shift_days(-1_000_000), Err(TimeError::PastOverflow));
+    }
+
+    #[test]
+    fn reflect()
+    {
+        let t = TimeValue::from_ticks;
+        let tw = TimeSpan::new(t(0), t(10)) | TimeSpan::new(t(20), t(30)) | TimeSpan::after(t(50));
+        let reflected = tw.reflect(t(5));
+        assert_eq!( reflected, TimeSpan::before(t(-40)) | TimeSpan::new(t(-20), t(-10)) | TimeSpan::new(t(0), t(10)));
+        assert_eq!( reflected.reflect(t(5)), tw);
+        assert_eq!( tw.reflect(t(0)), -tw.clone());
+        assert_eq!( TimeSpans::all().reflect(t(5)), TimeSpans::all());
+        assert!( TimeSpans::empty().reflect(t(5)).is_empty());
+
+        let slots = TimeSlot::new(h(1), h(2)) | TimeSlot::new(h(8), h(9));
+        assert_eq!( slots.reflect(h(5)), TimeSlot::new(h(1), h(2)) | TimeSlot::new(h(8), h(9)));
+        assert_eq!( slots.reflect(h(10)), TimeSlot::new(h(11), h(12)) | TimeSlot::new(h(18), h(19)));
+        assert_eq!( slots.reflect(h(10)).reflect(h(10)), slots);
+    }
+
+    #[test]
+    fn periodic()
+    {
+        let t = TimeValue::from_ticks;
+        assert_eq!( TimeSpans::periodic(t(5), t(10), t(2), 3), TimeSpan::new(t(5), t(7)) | TimeSpan::new(t(15), t(17)) | TimeSpan::new(t(25), t(27)) );
+        assert_eq!( TimeSpans::periodic(t(5), t(10), t(0), 2), TimeSpan::singleton(t(5)) | TimeSpan::singleton(t(15)) );
+        assert_eq!( TimeSpans::periodic(t(5), t(10), t(2), 0), TimeSpans::empty() );
+        assert_eq!( TimeSpans::periodic(t(5), t(3), t(2), 4), TimeSpan::new(t(5), t(16)) );
+        assert_eq!( TimeSpans::periodic(t(0), TimeValue::INFINITE, t(2), 4), TimeSpan::new(t(0), t(2)) );
+
+        // the intervals which end after the finite range are dropped
+        let inf = TimeValue::INFINITE.as_ticks();
+        let contiguous = TimeSpans::periodic(t(inf-20), t(5), t(4), 10);
+        assert_eq!( contiguous, TimeSpan::new(t(inf-20), t(inf-1)) );
+        assert!( contiguous.is_up_bounded() );
+        let sparse = TimeSpans::periodic(t(inf-20), t(6), t(4), 10);
+        assert_eq!( sparse, TimeSpan::new(t(inf-20), t(inf-16)) | TimeSpan::new(t(inf-14), t(inf-10)) | TimeSpan::new(t(inf-8), t(inf-4)) );
+        assert!( sparse.is_up_bounded() );
+        assert_eq!( TimeSpans::periodic(t(inf-2), t(6), t(4), 10), TimeSpans::empty() );
+        assert_eq!( TimeSpans::periodic(-TimeValue::INFINITE, t(6), t(4), 10), TimeSpans::empty() );
+
+        let daily = TimeSpans::periodic(TimeValue::from_hours(9), TimeValue::from_days(1), TimeValue::from_hours(8), 5);
+        assert_eq!( daily.convex_count(), 5 );
+        assert_eq!( daily.convex_envelope(), TimeSpan::new(TimeValue::from_hours(9), TimeValue::from_days(4) + TimeValue::from_hours(17)) );
+    }
+
+    #[test]
+    #[should_panic]
+    fn periodic_overlap()
+    {
+        let t = TimeValue::from_ticks;
+        let _ = TimeSpans::periodic(t(5), t(2), t(2), 3);
+    }
+
+    #[test]
+    fn capacity()
+    {
+        let t = TimeValue::from_ticks;
+        let mut set = TimeSpans::with_capacity(10);
+        assert!( set.is_empty() );
+        assert!( set.capacity() >= 10 );
+
+        set |= TimeSpan::new(t(0), t(5));
+        set |= TimeSpan::new(t(10), t(15));
+        set.reserve(20);
+        assert!( set.capacity() >= 22 );
+        set.shrink_to_fit();
+        assert!( set.capacity() >= 2 && set.capacity() < 22 );
+        assert_eq!( set, TimeSpan::new(t(0), t(5)) | TimeSpan::new(t(10), t(15)) );
+    }
+
+    #[test]
+    fn intersection_many()
+    {
+        let t = TimeValue::from_ticks;
+        let a: TimeSpans = (0..50).map(|i| TimeSpan::new(t(10*i), t(10*i+5))).collect();
+        let b: TimeSpans = (0..30).map(|i| TimeSpan::new(t(17*i+3), t(17*i+9))).collect();
+        let c: TimeSpans = (0..20).map(|i| TimeSpan::new(t(23*i), t(23*i+15))).collect();
+        let d = TimeSpans::from(TimeSpan::new(t(40), t(300)));
+
+        assert_eq!( TimeSet::intersection_many(&[&a, &b, &c, &d]), &(&(&a & &b) & &c) & &d);
+        assert_eq!( TimeSet::intersection_many(&[&a, &b]), &a & &b);
+        assert_eq!( TimeSet::intersection_many(&[&a]), a);
+        assert_eq!( TimeSet::intersection_many(&[&a, &TimeSpans::empty(), &b]), TimeSpans::empty());
+        assert_eq!( TimeSet::intersection_many(&[&a, &TimeSpans::all()]), a);
+        assert_eq!( TimeSet::<TimeValue>::intersection_many(&[]), TimeSpans::all());
+    }
+
+    #[test]
+    fn next_free()
+    {
+        let hours = TimeValue::from_hours;
+        let busy = TimeSlot::new(h(8), h(10)) | TimeSlot::new(h(11), h(14)) | TimeSlot::new(h(15), h(16));
+
+        assert_eq!( busy.next_free(h(0), hours(2)), Some(TimeSlot::new(h(0), h(2))));
+        assert_eq!( busy.next_free(h(9), TimeValue::from_mins(30)), Some(TimeSlot::new(h(10).just_after(), h(10).just_after() + TimeValue::from_mins(30))));
+        assert_eq!( busy.next_free(h(9), hours(1)), Some(TimeSlot::new(h(16).just_after(), h(17).just_after())));
+        assert_eq!( busy.next_free(h(20), hours(100)), Some(TimeSlot::new(h(20), h(120))));
+        assert_eq!( busy.next_free(h(12), TimeValue::default()), Some(TimeSlot::singleton(h(14).just_after())));
+        assert_eq!( TimeSlots::all().next_free(h(0), hours(1)), None);
+        assert_eq!( busy.next_free(h(0), -hours(1)), None);
+    }
+
+    #[test]
+    fn approx_eq()
+    {
+        let t = TimeValue::from_ticks;
+        let set = TimeSpan::new(t(0), t(10)) | TimeSpan::new(t(20), t(30));
+        let scaled = (set.clone() * 3.3_f64) / 3.3_f64;
+        assert!( scaled.approx_eq(&set, t(1)));
+        assert!( (TimeSpan::new(t(1), t(9)) | TimeSpan::new(t(21), t(31))).approx_eq(&set, t(1)));
+        assert!( !(TimeSpan::new(t(1), t(9)) | TimeSpan::new(t(21), t(31))).approx_eq(&set, t(0)));
+        assert!( !TimeSpans::from(TimeSpan::new(t(0), t(30))).approx_eq(&set, t(10)));
+        assert!( TimeSpans::empty().approx_eq(&TimeSpans::empty(), t(0)));
+    }
+
+    #[test]
+    fn collect_unsorted()
+    {
+        let t = TimeValue::from_ticks;
+        let parts = [
+            TimeSpan::new(t(40), t(50)),
+            TimeSpan::new(t(0), t(5)),
+            TimeSpan::empty(),
+            TimeSpan::new(t(12), t(20)),
+            TimeSpan::new(t(6), t(8)),
+            TimeSpan::new(t(45), t(60)),
+            TimeSpan::new(t(10), t(15)),
+            TimeSpan::singleton(t(70)),
+            TimeSpan::new(t(42), t(43)),
+        ];
+        let expected = TimeSpan::new(t(0), t(8)) | TimeSpan::new(t(10), t(20))
+            | TimeSpan::new(t(40), t(60)) | TimeSpan::singleton(t(70));
+        assert_eq!( parts.iter().copied().collect::<TimeSpans>(), expected);
+        assert_eq!( parts.iter().rev().copied().collect::<TimeSpans>(), expected);
+        assert_eq!( parts.iter().copied().fold(TimeSpans::empty(), |r,i| r|i), expected);
+
+        let unbounded = [TimeSpan::after(t(5)), TimeSpan::before(t(-5)), TimeSpan::new(t(-4), t(4))];
+        assert_eq!( unbounded.into_iter().collect::<TimeSpans>(), TimeSpans::all());
+        assert!( [TimeSpan::empty()].into_iter().collect::<TimeSpans>().is_empty());
+    }
+
+    #[test]
+    fn chunks_by_duration()
+    {
+        let t = TimeValue::from_ticks;
+        let set = TimeSpan::new(t(0), t(3)) | TimeSpan::new(t(10), t(20)) | TimeSpan::singleton(t(30));
+        let chunks = set.chunks_by_duration(t(5));
+        assert_eq!( chunks, vec![
+            TimeSpan::new(t(0), t(3)) | TimeSpan::new(t(10), t(12)),
+            TimeSpans::from(TimeSpan::new(t(13), t(18))),
+            TimeSpan::new(t(19), t(20)) | TimeSpan::singleton(t(30)),
+        ]);
+        assert_eq!( chunks.into_iter().collect::<TimeSpans>(), set);
+
+        // a long part is split in several chunks
+        let chunks = TimeSpans::from(TimeSpan::new(t(0), t(10))).chunks_by_duration(t(4));
+        assert_eq!( chunks, vec![
+            TimeSpans::from(TimeSpan::new(t(0), t(4))),
+            TimeSpans::from(TimeSpan::new(t(5), t(9))),
+            TimeSpans::from(TimeSpan::singleton(t(10))),
+        ]);
+        assert!( TimeSpans::empty().chunks_by_duration(t(4)).is_empty());
+
+        let slots = TimeSlots::from(TimeSlot::new(h(8), h(12)));
+        assert_eq!( slots.chunks_by_duration(TimeValue::from_hours(4)), vec![slots]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunks_by_null_duration()
+    {
+        TimeSpans::from(TimeSpan::new(TimeValue::from_ticks(0), TimeValue::from_ticks(10)))
+            .chunks_by_duration(TimeValue::default());
+    }
+
+    #[test]
+    fn iter_gaps()
+    {
+        let t = TimeValue::from_ticks;
+        let set = TimeSpan::new(t(1), t(5)) | TimeSpan::new(t(10), t(20));
+        assert_eq!( set.iter_gaps().collect::<TimeSpans>(), TimeSpan::new(t(6), t(9)));
+
+        let set = TimeSpan::before(t(5)) | TimeSpan::singleton(t(7)) | TimeSpan::after(t(10));
+        assert_eq!( set.iter_gaps().collect::<TimeSpans>(), TimeSpan::singleton(t(6)) | TimeSpan::new(t(8), t(9)));
+        assert_eq!( set.iter_gaps().collect::<TimeSpans>(), !set.clone() & set.convex_envelope());
+
+        assert_eq!( TimeSpans::from(TimeSpan::new(t(1), t(5))).iter_gaps().count(), 0);
+        assert_eq!( TimeSpans::all().iter_gaps().count(), 0);
+        assert_eq!( TimeSpans::empty().iter_gaps().count(), 0);
+    }
+
+    #[test]
+    fn is_disjoint()
+    {
+        let t = TimeValue::from_ticks;
+        let busy = TimeSpan::new(t(0), t(10)) | TimeSpan::new(t(20), t(30)) | TimeSpan::after(t(50));
+
+        assert!( busy.is_disjoint(&(TimeSpan::new(t(11), t(19)) | TimeSpan::new(t(31), t(49)))));
+        assert!( busy.is_disjoint(&TimeSpans::from(TimeSpan::before(t(-1)))));
+        assert!( !busy.is_disjoint(&busy));
+        assert!( !busy.is_disjoint(&(TimeSpan::new(t(11), t(19)) | TimeSpan::singleton(t(30)))));
+        assert!( !busy.is_disjoint(&TimeSpans::from(TimeSpan::after(t(1000)))));
+        assert!( !busy.is_disjoint(&TimeSpans::all()));
+        assert!( busy.is_disjoint(&!busy.clone()));
+
+        assert!( busy.is_disjoint(&TimeSpans::empty()));
+        assert!( TimeSpans::empty().is_disjoint(&busy));
+        assert!( TimeSpans::empty().is_disjoint(&TimeSpans::empty()));
+        assert!( TimeSpans::empty().is_disjoint(&TimeSpans::all()));
+
+        assert_eq!( busy.is_disjoint(&TimeSpans::from(TimeSpan::new(t(5), t(25)))), !busy.overlaps(&TimeSpans::from(TimeSpan::new(t(5), t(25)))));
+    }
+
+    #[test]
+    fn histogram()
+    {
+        let m = TimeValue::from_mins;
+        let busy = TimeSpan::new(m(30), m(90)) | TimeSpan::new(m(100), m(110)) | TimeSpan::new(m(170), m(200));
+
+        assert_eq!( busy.histogram(TimeValue::default(), m(60)), vec![
+            (TimeSpan::new(m(0), m(60).just_before()), m(30)),
+            (TimeSpan::new(m(60), m(120).just_before()), m(40)),
+            (TimeSpan::new(m(120), m(180).just_before()), m(10)),
+            (TimeSpan::new(m(180), m(240).just_before()), m(20)),
+        ]);
+        // the buckets are aligned on the origin, even after the set
+        assert_eq!( busy.histogram(m(1000), m(60)).first(), Some(&(TimeSpan::new(m(-20), m(40).just_before()), m(10))));
+        assert_eq!( busy.histogram(m(30), m(200)), vec![(TimeSpan::new(m(30), m(230).just_before()), m(100))]);
+
+        assert!( TimeSpans::empty().histogram(m(0), m(60)).is_empty());
+        assert_eq!( TimeSpans::from(TimeSpan::singleton(m(5))).histogram(m(0), m(60)), vec![(TimeSpan::new(m(0), m(60).just_before()), m(0))]);
+    }
+
+    #[test]
+    fn difference_metrics()
+    {
+        let h = TimeValue::from_hours;
+        let busy = TimeSpan::new(h(0), h(2)) | TimeSpan::new(h(4), h(8)) | TimeSpan::new(h(10), h(12));
+
+        let freed = TimeSpan::new(h(1), h(5)) | TimeSpan::new(h(6), h(7));
+        assert_eq!( busy.difference_metrics(&freed), (2, h(3)));
+
+        assert_eq!( busy.difference_metrics(&busy), (3, h(8)));
+        assert_eq!( busy.difference_metrics(&TimeSpans::from(TimeSpan::new(h(2), h(4)))), (2, h(0)));
+        assert_eq!( busy.difference_metrics(&TimeSpans::from(TimeSpan::new(h(13), h(14)))), (0, h(0)));
+        assert_eq!( busy.difference_metrics(&TimeSpans::empty()), (0, h(0)));
+        assert_eq!( TimeSpans::empty().difference_metrics(&busy), (0, h(0)));
+    }
+
+    #[test]
+    fn contains_all()
+    {
+        let t = TimeValue::from_ticks;
+        let avail = TimeSpan::new(t(0), t(10)) | TimeSpan::new(t(20), t(30)) | TimeSpan::after(t(50));
+
+        assert!( avail.contains_all(&[t(0), t(5), t(10), t(20), t(1000)]));
+        assert!( avail.contains_all(&[t(1000), t(5), t(25), t(0)]));
+        assert!( !avail.contains_all(&[t(0), t(15), t(20)]));
+        assert!( !avail.contains_all(&[t(20), t(40), t(0)]));
+        assert!( avail.contains_all(&[]));
+        assert!( !TimeSpans::empty().contains_all(&[t(0)]));
+        assert!( TimeSpans::all().contains_all(&[TimeValue::INFINITE, -TimeValue::INFINITE]));
+
+        let points = [t(-1), t(0), t(11), t(25), t(30), t(31), t(50), t(60)];
+        let expected = vec![false, true, false, true, true, false, true, true];
+        assert_eq!( avail.which_contained(&points), expected);
+
+        // unsorted points give the same answers
+        let mut shuffled = points;
+        shuffled.reverse();
+        let mut reversed = expected.clone();
+        reversed.reverse();
+        assert_eq!( avail.which_contained(&shuffled), reversed);
+        assert!( avail.which_contained(&[]).is_empty());
+    }
+
+    #[test]
+    fn from_pairs()
+    {
+        let t = TimeValue::from_ticks;
+        assert_eq!( TimeSpans::from_pairs(&[(t(20), t(30)), (t(0), t(10)), (t(5), t(12)), (t(31), t(40))]),
+                    Ok(TimeSpan::new(t(0), t(12)) | TimeSpan::new(t(20), t(40))));
+        assert_eq!( TimeSpans::from_pairs(&[(t(3), t(3))]), Ok(TimeSpans::singleton(t(3))));
+        assert_eq!( TimeSpans::from_pairs(&[]), Ok(TimeSpans::empty()));
+        assert_eq!( TimeSpans::from_pairs(&[(t(0), t(10)), (t(5), t(2))]), Err(TimeError::EmptyInterval));
+
+        assert_eq!( TimeSpans::from_points(&[t(5), t(1), t(5), t(2), t(10)]),
+                    TimeSpan::new(t(1), t(2)) | TimeSpan::singleton(t(5)) | TimeSpan::singleton(t(10)));
+        assert!( TimeSpans::from_points(&[]).is_empty());
+    }
+
+    #[test]
+    fn covers()
+    {
+        let t = TimeValue::from_ticks;
+        let avail = TimeSpan::new(t(0), t(10)) | TimeSpan::new(t(20), t(30)) | TimeSpan::after(t(50));
+
+        assert!( avail.covers(&avail));
+        assert!( avail.covers(&TimeSpans::empty()));
+        assert!( TimeSpans::empty().covers(&TimeSpans::empty()));
+        assert!( !TimeSpans::empty().covers(&avail));
+
+        assert!( avail.covers(&(TimeSpan::new(t(2), t(3)) | TimeSpan::new(t(5), t(10)) | TimeSpan::new(t(25), t(30)))));
+        assert!( avail.covers(&(TimeSpan::singleton(t(20)) | TimeSpan::new(t(60), t(70)))));
+        assert!( avail.covers(&TimeSpans::from(TimeSpan::after(t(51)))));
+        assert!( TimeSpans::all().covers(&avail));
+
+        // overlaps a gap
+        assert!( !avail.covers(&TimeSpans::from(TimeSpan::new(t(5), t(25)))));
+        assert!( !avail.covers(&(TimeSpan::new(t(2), t(3)) | TimeSpan::singleton(t(15)))));
+        assert!( !avail.covers(&(TimeSpan::new(t(2), t(3)) | TimeSpan::new(t(29), t(31)))));
+        assert!( !avail.covers(&TimeSpans::all()));
+
+        // the same result than the generic containing
+        assert!( avail.contains(&(TimeSpan::new(t(2), t(3)) | TimeSpan::new(t(25), t(30)))));
+        assert!( !avail.contains(&(TimeSpan::new(t(2), t(3)) | TimeSpan::singleton(t(15)))));
+    }
+
+    #[test]
+    fn at_origin()
+    {
+        let t = TimeValue::from_secs;
+        let origin = Timestamp::from_origin(TimeValue::from_days(3));
+        let spans = TimeSpan::new(t(1), t(5)) | TimeSpan::new(t(10), t(20)) | TimeSpan::after(t(30));
+
+        let slots = spans.at_origin(origin);
+        assert_eq!( slots, spans.clone() + origin );
+        assert_eq!( slots.lower_bound(), origin + t(1) );
+        assert_eq!( slots.relative_to(origin), spans );
+
+        let before = TimeSlots::from(TimeSlot::before(origin));
+        assert_eq!( before.relative_to(origin), TimeSpans::from(TimeSpan::before(t(0))) );
+        assert_eq!( before.relative_to(origin).at_origin(origin), before );
+        assert_eq!( TimeSlots::empty().relative_to(origin), TimeSpans::empty() );
+    }
+
+    #[test]
+    fn map_intervals()
+    {
+        let t = TimeValue::from_ticks;
+        let set = TimeSpan::new(t(1), t(5)) | TimeSpan::new(t(10), t(20)) | TimeSpan::new(t(30), t(32));
+
+        // each part is enlarged so they overlap
+        assert_eq!( set.map_intervals(|tw| tw.enlarge(t(3)).unwrap()), TimeSpan::new(t(-2), t(23)) | TimeSpan::new(t(27), t(35)) );
+        // the parts are reordered
+        assert_eq!( set.map_intervals(|tw| TimeSpan::new(t(100) - tw.upper, t(100) - tw.lower)), set.reflect(t(50)) );
+        // the empty parts are dropped
+        assert_eq!( set.map_intervals(|tw| if tw.lower > t(5) { tw } else { TimeSpan::empty() }), TimeSpan::new(t(10), t(20)) | TimeSpan::new(t(30), t(32)) );
+        assert_eq!( set.map_intervals(|_| TimeSpan::singleton(t(0))), TimeSpan::singleton(t(0)) );
+        assert_eq!( TimeSpans::empty().map_intervals(|_| TimeSpan::all()), TimeSpans::empty() );
+    }
+
+    #[test]
+    fn map_points()
+    {
+        let t = TimeValue::from_ticks;
+        let set = TimeSpan::new(t(1), t(5)) | TimeSpan::new(t(10), t(20)) | TimeSpan::after(t(30));
+
+        assert_eq!( set.map_points(|x| x + t(3)), set.clone() + t(3) );
+        assert_eq!( set.map_points(|x| x), set );
+
+        // a warp which makes the parts touch each other
+        let warp = |x: TimeValue| if x <= t(5) { x } else { x - t(4) };
+        assert_eq!( set.map_points(warp), TimeSpan::new(t(1), t(16)) | TimeSpan::after(t(26)) );
+
+        // a warp which collapses all the bounds
+        let set = TimeSpan::new(t(1), t(5)) | TimeSpan::new(t(10), t(20));
+        assert_eq!( set.map_points(|_| t(0)), TimeSpan::singleton(t(0)) );
+        assert_eq!( TimeSpans::empty().map_points(|x| x), TimeSpans::empty() );
+    }
+}
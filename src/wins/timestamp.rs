@@ -1,5 +1,7 @@
-use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
-use chrono::{DateTime, NaiveDateTime, TimeZone, Utc};
+use core::ops::{Add, AddAssign, Neg, Sub, SubAssign};
+#[cfg(feature = "std")]
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday};
+#[cfg(feature = "std")]
 use std::time::SystemTime;
 
 use crate::*;
@@ -17,21 +19,228 @@ pub trait Timestamped {
 
 impl Timestamp {
 
+    /// The origin of timestamps (1970-01-01 00:00:00 UTC)
+    pub const EPOCH: Timestamp = Timestamp(TimeValue(0));
+
+    /// The latest finite timestamp
+    ///
+    /// Notice that there is no finite timestamp after it, so
+    /// `MAX.just_after()` stays `MAX` (see [`Self::just_after`]).
+    pub const MAX: Timestamp = Timestamp(TimeValue(INFINITE_TIME_VALUE-1));
+
+    /// The earliest finite timestamp
+    ///
+    /// Notice that there is no finite timestamp before it, so
+    /// `MIN.just_before()` stays `MIN` (see [`Self::just_before`]).
+    pub const MIN: Timestamp = Timestamp(TimeValue(1-INFINITE_TIME_VALUE));
+
+    /// The timestamp just after this one, without leaving the finite timestamps
+    ///
+    /// This is the next tick, except for [`Self::MAX`] which stays `MAX` (and the
+    /// infinite timestamps which remain infinite): so a finite bound never turns
+    /// silently into an infinite one.
+    ///
+    /// Notice that [`TimePoint::just_after`], used by the generic time window
+    /// computations, goes from `MAX` to +&infin; since it is used to build the
+    /// bounds of complementary intervals (e.g. nothing remains after `[t,MAX]`).
+    #[inline]
+    pub fn just_after(&self) -> Self {
+        if *self == Self::MAX { *self } else { TimePoint::just_after(self) }
+    }
+
+    /// The timestamp just before this one, without leaving the finite timestamps
+    ///
+    /// This is the previous tick, except for [`Self::MIN`] which stays `MIN`
+    /// (see [`Self::just_after`]).
+    #[inline]
+    pub fn just_before(&self) -> Self {
+        if *self == Self::MIN { *self } else { TimePoint::just_before(self) }
+    }
+
     /// Creates a timepoint relative to the origin
     #[inline]
     pub fn from_origin(t: TimeValue) -> Self { Self(t) }
 
+    #[cfg(feature = "std")]
     #[inline]
     pub fn now() -> Self {
         Self(SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap().into())
     }
 
+    /// The current timestamp floored to a period (see [`Clock::now_truncated`])
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn now_truncated(precision: TimeValue) -> Self { SystemClock.now_truncated(precision) }
+
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn elapsed(&self) -> TimeValue { self.elapsed_with(&SystemClock) }
+
+    /// Duration since this timestamp according to a clock
     #[inline]
-    pub fn elapsed(&self) -> TimeValue { Self::now() - *self }
+    pub fn elapsed_with(&self, clock: &impl Clock) -> TimeValue { clock.now() - *self }
 
+    #[cfg(feature = "std")]
     #[inline]
     pub fn to_datetime(&self) -> DateTime<Utc> { Utc.from_utc_datetime(&(*self).into()) }
 
+    /// Converts this timestamp to a date time of the given time zone
+    ///
+    /// # Panics
+    /// Panics if the timestamp is out of the range of the dates (e.g. infinite).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn to_datetime_in<Tz:TimeZone>(&self, tz: &Tz) -> DateTime<Tz> {
+        tz.from_utc_datetime(&(*self).into())
+    }
+
+    /// Formats this timestamp as a date time of the given time zone
+    ///
+    /// The format follows the `strftime` syntax of [`chrono::format::strftime`].
+    /// Infinite timestamps are displayed as `+oo` and `-oo`.
+    #[cfg(feature = "std")]
+    pub fn format_in<Tz:TimeZone>(&self, tz: &Tz, fmt: &str) -> String
+        where Tz::Offset: core::fmt::Display
+    {
+        if self.is_future_infinite() {
+            "+oo".to_string()
+        } else if self.is_past_infinite() {
+            "-oo".to_string()
+        } else {
+            self.to_datetime_in(tz).format(fmt).to_string()
+        }
+    }
+
+    /// The day of the week (UTC)
+    ///
+    /// # Panics
+    /// Panics if the timestamp is out of the range of the dates (e.g. infinite).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn weekday(&self) -> Weekday { self.date().weekday() }
+
+    /// The calendar date (UTC)
+    ///
+    /// # Panics
+    /// Panics if the timestamp is out of the range of the dates (e.g. infinite).
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn date(&self) -> NaiveDate { NaiveDateTime::from(*self).date() }
+
+    /// The duration since the last midnight (UTC)
+    ///
+    /// The result is in `[0,24h[` (even for the dates before 1970) and
+    /// keeps the tick precision. It is zero for infinite timestamps.
+    #[inline]
+    pub fn time_of_day(&self) -> TimeValue
+    {
+        if self.is_finite() {
+            self.0 - self.0.floor(TimeValue::from_days(1))
+        } else {
+            TimeValue::default()
+        }
+    }
+
+    /// The hour of the day in `0..24` (UTC)
+    #[inline]
+    pub fn hour(&self) -> u32 { self.time_of_day().as_secs() as u32 / 3600 }
+
+    /// The midnight which starts the day of this timestamp (UTC)
+    ///
+    /// Since the origin is a midnight (and there is no leap second),
+    /// this is also the timestamp floored to a multiple of a day.
+    /// Infinite timestamps are unchanged.
+    #[inline]
+    pub fn start_of_day(&self) -> Timestamp { *self - self.time_of_day() }
+
+    /// The last tick of the day of this timestamp (UTC)
+    ///
+    /// This is the timestamp just before the next midnight, so the day of
+    /// the timestamp is `[start_of_day, end_of_day]`.
+    /// Infinite timestamps are unchanged.
+    #[inline]
+    pub fn end_of_day(&self) -> Timestamp
+    {
+        if self.is_finite() {
+            (self.start_of_day() + TimeValue::from_days(1)).just_before()
+        } else {
+            *self
+        }
+    }
+
+    /// The midnight which starts the week (on monday) of this timestamp (UTC)
+    ///
+    /// Infinite timestamps are unchanged.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn start_of_week(&self) -> Timestamp
+    {
+        if self.is_finite() {
+            self.start_of_day() - TimeValue::from_days(self.weekday().num_days_from_monday() as i64)
+        } else {
+            *self
+        }
+    }
+
+    /// The midnight which starts the month of this timestamp (UTC)
+    ///
+    /// The calendar is used, so the months have their actual lengths
+    /// (unlike a floor to [`TimeValue::from_months`] which is an average duration).
+    /// Infinite timestamps are unchanged.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn start_of_month(&self) -> Timestamp
+    {
+        if self.is_finite() {
+            self.start_of_day() - TimeValue::from_days(self.date().day0() as i64)
+        } else {
+            *self
+        }
+    }
+
+    /// Adds a number of calendar months (possibly negative)
+    ///
+    /// Unlike the addition of [`TimeValue::from_months`] (which is an average
+    /// duration of a month), the day of the month and the time of the day are kept
+    /// (e.g. 2024-03-15 10:00 plus one month is 2024-04-15 10:00).
+    /// If the day does not exist in the resulting month, the last day of this
+    /// month is taken (e.g. January 31st plus one month is February 28th or 29th).
+    ///
+    /// Infinite timestamps are unchanged and if the resulting date is out of
+    /// range, the result is saturated to infinite.
+    #[cfg(feature = "std")]
+    pub fn add_months(self, n: i32) -> Timestamp
+    {
+        if !self.is_finite() {
+            return self;
+        }
+        let date = NaiveDateTime::from(self);
+        // the ticks which are lost by the conversion to nanoseconds
+        let subnanos = self - Timestamp::from(date);
+        let months = Months::new(n.unsigned_abs());
+        let shifted = if n >= 0 {
+            date.checked_add_months(months)
+        } else {
+            date.checked_sub_months(months)
+        };
+        match shifted {
+            Some(date) => Timestamp::from(date) + subnanos,
+            None if n >= 0 => Timestamp::INFINITE,
+            None => -Timestamp::INFINITE,
+        }
+    }
+
+    /// Adds a number of calendar years (possibly negative)
+    ///
+    /// This is the same as adding `12*n` calendar months (see [`Self::add_months`]),
+    /// so February 29th plus one year is February 28th.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn add_years(self, n: i32) -> Timestamp
+    {
+        self.add_months(n.saturating_mul(12))
+    }
+
     #[inline]
     pub fn floor(self, period:TimeValue) -> Self
     {
@@ -47,12 +256,98 @@ impl Timestamp {
     /// Duration since origin
     #[inline]
     pub fn since_origin(self) -> TimeValue { self.0 }
+
+    /// Timestamp from a number of seconds since the Unix epoch
+    ///
+    /// An integer out of the range of the finite timestamps (e.g. `i64::MAX`)
+    /// gives an infinite timestamp.
+    #[inline]
+    pub fn from_unix_secs(secs: i64) -> Self { Self(TimeValue::from_secs(secs)) }
+
+    /// Timestamp from a number of milliseconds since the Unix epoch
+    #[inline]
+    pub fn from_unix_millis(millis: i64) -> Self { Self(TimeValue::from_millis(millis)) }
+
+    /// Timestamp from a number of microseconds since the Unix epoch
+    #[inline]
+    pub fn from_unix_micros(micros: i64) -> Self { Self(TimeValue::from_micros(micros)) }
+
+    /// Timestamp from a number of nanoseconds since the Unix epoch
+    #[inline]
+    pub fn from_unix_nanos(nanos: i64) -> Self { Self(TimeValue::from_nanos(nanos)) }
+
+    /// Number of seconds since the Unix epoch
+    ///
+    /// The result is floored (i.e. truncated toward the past) so it is the
+    /// greatest number of seconds whose timestamp is not after this one.
+    /// The infinite timestamps are mapped to `i64::MAX` and `i64::MIN`.
+    #[inline]
+    pub fn to_unix_secs(&self) -> i64 { self.unix_units(1, TimeValue::from_secs) }
+
+    /// Number of milliseconds since the Unix epoch (see [`Self::to_unix_secs`])
+    #[inline]
+    pub fn to_unix_millis(&self) -> i64 { self.unix_units(1_000, TimeValue::from_millis) }
+
+    /// Number of microseconds since the Unix epoch (see [`Self::to_unix_secs`])
+    #[inline]
+    pub fn to_unix_micros(&self) -> i64 { self.unix_units(1_000_000, TimeValue::from_micros) }
+
+    /// Number of nanoseconds since the Unix epoch (see [`Self::to_unix_secs`])
+    #[inline]
+    pub fn to_unix_nanos(&self) -> i64 { self.unix_units(1_000_000_000, TimeValue::from_nanos) }
+
+    /// Mirrors this timestamp around the origin ([`Self::EPOCH`])
+    ///
+    /// The result is as far before the origin as this timestamp is after it
+    /// (and the infinite timestamps are swapped).
+    /// This is the explicit form of `-timestamp`, which is mainly required
+    /// for the generic time computations (see [`TimePoint`]).
+    #[inline]
+    pub fn reflect_origin(self) -> Timestamp { Self(-self.0) }
+
+    /// Linear interpolation between two timestamps
+    ///
+    /// Returns `a + (b-a)*t`, rounded to the nearest tick
+    /// (see [`TimeValue::lerp`]).
+    #[inline]
+    pub fn lerp(a: Timestamp, b: Timestamp, t: f64) -> Timestamp
+    {
+        Self(TimeValue::lerp(a.0, b.0, t))
+    }
+
+    /// Linear interpolation between two timestamps, restricted to them
+    ///
+    /// The factor `t` is clamped to `[0,1]` (see [`TimeValue::lerp_clamped`]).
+    #[inline]
+    pub fn lerp_clamped(a: Timestamp, b: Timestamp, t: f64) -> Timestamp
+    {
+        Self(TimeValue::lerp_clamped(a.0, b.0, t))
+    }
+
+    fn unix_units(&self, per_sec: i128, from_unix: fn(i64) -> TimeValue) -> i64
+    {
+        if self.is_future_infinite() {
+            i64::MAX
+        } else if self.is_past_infinite() {
+            i64::MIN
+        } else {
+            // a unit is not an exact number of ticks so the floored value
+            // could be one unit less than expected: checks the next one
+            let n = ((self.0.as_ticks() as i128 * per_sec) >> SUBSEC_BITLEN) as i64;
+            if from_unix(n+1) <= self.0 { n+1 } else { n }
+        }
+    }
 }
 
 
+/// Reflection around the origin
+///
+/// Negating a date makes sense only for the internal time computations
+/// (it is required by [`TimePoint`]): prefer [`Timestamp::reflect_origin`]
+/// which makes the intent explicit.
 impl Neg for Timestamp {
     type Output = Self;
-    #[inline] fn neg(self) -> Self::Output { Self(-self.0) }
+    #[inline] fn neg(self) -> Self::Output { self.reflect_origin() }
 }
 
 impl TimePoint for Timestamp
@@ -90,28 +385,36 @@ impl<T:Timestamped> Timestamped for &T
     #[inline] fn timestamp(&self) -> Timestamp { T::timestamp(self) }
 }
 
+#[cfg(feature = "std")]
 impl From<Timestamp> for NaiveDateTime
 {
     #[inline]
     fn from(value: Timestamp) -> Self {
-        NaiveDateTime::from_timestamp_opt( value.0.as_secs(), value.0.subsec_nanos() as u32).unwrap()
+        DateTime::from_timestamp( value.0.as_secs(), value.0.subsec_nanos() as u32).unwrap().naive_utc()
     }
 }
 
+#[cfg(feature = "std")]
 impl From<NaiveDateTime> for Timestamp
 {
+    /// Converts a UTC date (saturated to infinite if out of range)
     #[inline]
-    fn from(t: NaiveDateTime) -> Self {
-        Self(TimeValue::from_nanos(t.timestamp_nanos_opt().unwrap()))
-    }
+    fn from(t: NaiveDateTime) -> Self { Self::from(t.and_utc()) }
 }
 
 
+#[cfg(feature = "std")]
 impl<Tz:TimeZone> From<DateTime<Tz>> for Timestamp
 {
+    /// Converts a date (saturated to infinite if out of range)
     #[inline]
     fn from(t: DateTime<Tz>) -> Self {
-        Self(TimeValue::from_nanos(t.timestamp_nanos_opt().unwrap()))
+        // the dates which can't be counted in nanoseconds are far out of the timestamp range
+        match t.timestamp_nanos_opt() {
+            Some(nanos) => Self(TimeValue::from_nanos(nanos)),
+            None if t.timestamp() > 0 => Self::INFINITE,
+            None => -Self::INFINITE,
+        }
     }
 }
 
@@ -156,3 +459,202 @@ impl Sub<Timestamp> for TimeValue {
     type Output = Timestamp;
     #[inline] fn sub(self, tw: Self::Output) -> Self::Output { (-tw) + self }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use crate::testing::h;
+
+    #[test]
+    fn constants()
+    {
+        assert_eq!( Timestamp::EPOCH, Timestamp::default());
+        assert_eq!( Timestamp::EPOCH.since_origin(), TimeValue::default());
+
+        assert!( Timestamp::MAX.is_finite());
+        assert!( Timestamp::MIN.is_finite());
+        assert!( Timestamp::MIN < Timestamp::EPOCH && Timestamp::EPOCH < Timestamp::MAX);
+        assert_eq!( Timestamp::MIN, -Timestamp::MAX);
+
+        assert_eq!( Timestamp::MAX.just_after(), Timestamp::MAX);
+        assert_eq!( Timestamp::MAX.just_before().just_after(), Timestamp::MAX);
+        assert_eq!( Timestamp::MIN.just_before(), Timestamp::MIN);
+        assert_eq!( Timestamp::MIN.just_after().just_before(), Timestamp::MIN);
+        assert_eq!( Timestamp::INFINITE.just_before(), Timestamp::INFINITE);
+        assert_eq!( (-Timestamp::INFINITE).just_after(), -Timestamp::INFINITE);
+
+        // the generic time point reaches the infinite (to build complementary bounds)
+        assert_eq!( TimePoint::just_after(&Timestamp::MAX), Timestamp::INFINITE);
+        assert_eq!( TimePoint::just_before(&Timestamp::MIN), -Timestamp::INFINITE);
+        assert!( (!TimeSlots::from(TimeSlot::before(Timestamp::MAX))).iter().all(|tw| tw.lower_bound() > Timestamp::MAX));
+
+        let deadline = Timestamp::from_origin(TimeValue::from_days(3));
+        assert_eq!( deadline.min(Timestamp::MAX), deadline);
+        assert_eq!( Timestamp::INFINITE.min(Timestamp::MAX), Timestamp::MAX);
+    }
+
+    #[test]
+    fn lerp()
+    {
+        assert_eq!( Timestamp::lerp(h(8), h(12), 0.25), h(9) );
+        assert_eq!( Timestamp::lerp(h(8), h(12), 1.5), h(14) );
+        assert_eq!( Timestamp::lerp_clamped(h(8), h(12), 1.5), h(12) );
+        assert_eq!( Timestamp::lerp(h(-8), h(8), 0.5), Timestamp::EPOCH );
+    }
+
+    #[test]
+    fn unix()
+    {
+        let t = Timestamp::from_unix_secs(1_700_000_000);
+        assert_eq!( t.since_origin(), TimeValue::from_secs(1_700_000_000));
+        assert_eq!( t.to_unix_secs(), 1_700_000_000);
+        assert_eq!( t.to_unix_millis(), 1_700_000_000_000);
+        assert_eq!( t.to_unix_nanos(), 1_700_000_000_000_000_000);
+
+        // round trips
+        for n in [0, 1, 7, 999, 1_000, 123_456_789, -1, -7, -999, -1_000, -123_456_789] {
+            assert_eq!( Timestamp::from_unix_secs(n).to_unix_secs(), n);
+            assert_eq!( Timestamp::from_unix_millis(n).to_unix_millis(), n);
+            assert_eq!( Timestamp::from_unix_micros(n).to_unix_micros(), n);
+            assert_eq!( Timestamp::from_unix_nanos(n).to_unix_nanos(), n);
+        }
+
+        // floored toward the past
+        let t = Timestamp::from_unix_millis(1_500);
+        assert_eq!( t.to_unix_secs(), 1);
+        assert_eq!( (-t).to_unix_secs(), -2);
+        assert_eq!( Timestamp::from_unix_millis(-1).to_unix_secs(), -1);
+        assert_eq!( Timestamp::from_unix_nanos(1_999_999).to_unix_millis(), 1);
+
+        assert_eq!( Timestamp::INFINITE.to_unix_millis(), i64::MAX);
+        assert_eq!( (-Timestamp::INFINITE).to_unix_secs(), i64::MIN);
+        assert_eq!( Timestamp::from_unix_secs(i64::MAX), Timestamp::INFINITE);
+        assert_eq!( Timestamp::from_unix_secs(i64::MIN), -Timestamp::INFINITE);
+    }
+
+    #[test]
+    fn reflect_origin()
+    {
+        let t = Timestamp::from_origin(TimeValue::from_days(3));
+        assert_eq!( t.reflect_origin(), Timestamp::from_origin(-TimeValue::from_days(3)));
+        assert_eq!( t.reflect_origin().reflect_origin(), t);
+        assert_eq!( t.reflect_origin(), -t);
+        assert_eq!( Timestamp::EPOCH.reflect_origin(), Timestamp::EPOCH);
+        assert_eq!( Timestamp::MAX.reflect_origin(), Timestamp::MIN);
+        assert_eq!( Timestamp::INFINITE.reflect_origin(), -Timestamp::INFINITE);
+
+        // the distance to the origin is kept
+        let d = Timestamp::from_unix_secs(1_700_000_000);
+        assert_eq!( Timestamp::EPOCH - d.reflect_origin(), d - Timestamp::EPOCH);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn time_zones()
+    {
+        let paris = chrono::FixedOffset::east_opt(3600).unwrap();
+        let t = Timestamp::from_unix_secs(1_700_000_000);
+        assert_eq!( t.to_datetime_in(&paris), t.to_datetime());
+        assert_eq!( t.format_in(&chrono::Utc, "%Y-%m-%d %H:%M"), "2023-11-14 22:13");
+        assert_eq!( t.format_in(&paris, "%Y-%m-%d %H:%M %z"), "2023-11-14 23:13 +0100");
+        assert_eq!( Timestamp::INFINITE.format_in(&paris, "%H:%M"), "+oo");
+        assert_eq!( (-Timestamp::INFINITE).format_in(&paris, "%H:%M"), "-oo");
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn calendar_boundaries()
+    {
+        use chrono::NaiveDate;
+        let date = |y,m,d,h| Timestamp::from(NaiveDate::from_ymd_opt(y,m,d).unwrap().and_hms_opt(h,0,0).unwrap());
+
+        let t = date(2024,2,29,10) + TimeValue::from_ticks(1);
+        assert_eq!( t.start_of_day(), date(2024,2,29,0));
+        assert_eq!( t.end_of_day(), date(2024,3,1,0).just_before());
+        assert_eq!( t.start_of_week(), date(2024,2,26,0));
+        assert_eq!( t.start_of_month(), date(2024,2,1,0));
+
+        assert_eq!( date(2024,2,26,0).start_of_week(), date(2024,2,26,0));
+        assert_eq!( date(2024,3,3,23).start_of_week(), date(2024,2,26,0));
+        assert_eq!( date(2024,3,31,23).start_of_month(), date(2024,3,1,0));
+        assert_eq!( date(2024,3,1,0).start_of_month(), date(2024,3,1,0));
+        assert_eq!( date(2024,3,1,0).just_before().start_of_month(), date(2024,2,1,0));
+
+        // before the origin
+        assert_eq!( date(1969,12,31,10).start_of_day(), date(1969,12,31,0));
+        assert_eq!( date(1969,12,31,10).start_of_week(), date(1969,12,29,0));
+        assert_eq!( date(1969,12,31,10).start_of_month(), date(1969,12,1,0));
+
+        assert_eq!( Timestamp::INFINITE.start_of_day(), Timestamp::INFINITE);
+        assert_eq!( (-Timestamp::INFINITE).end_of_day(), -Timestamp::INFINITE);
+        assert_eq!( Timestamp::INFINITE.start_of_month(), Timestamp::INFINITE);
+        assert_eq!( Timestamp::INFINITE.start_of_week(), Timestamp::INFINITE);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn add_months()
+    {
+        use chrono::NaiveDate;
+        let date = |y,m,d| Timestamp::from(NaiveDate::from_ymd_opt(y,m,d).unwrap().and_hms_opt(10,30,0).unwrap());
+
+        assert_eq!( date(2024,3,15).add_months(1), date(2024,4,15));
+        assert_eq!( date(2024,3,15).add_months(-3), date(2023,12,15));
+        assert_eq!( date(2023,1,31).add_months(1), date(2023,2,28));
+        assert_eq!( date(2024,1,31).add_months(1), date(2024,2,29));
+        assert_eq!( date(2024,5,31).add_months(-1), date(2024,4,30));
+        assert_eq!( date(2024,3,15).add_months(0), date(2024,3,15));
+        assert_ne!( date(2024,3,15).add_months(1), date(2024,3,15) + TimeValue::from_months(1));
+
+        assert_eq!( date(2024,2,29).add_years(1), date(2025,2,28));
+        assert_eq!( date(2024,2,29).add_years(4), date(2028,2,29));
+        assert_eq!( date(2024,7,1).add_years(-24), date(2000,7,1));
+
+        // the ticks are kept
+        let t = date(2024,3,15) + TimeValue::from_ticks(1);
+        assert_eq!( t.add_months(2), date(2024,5,15) + TimeValue::from_ticks(1));
+
+        assert_eq!( Timestamp::INFINITE.add_months(1), Timestamp::INFINITE);
+        assert_eq!( date(2024,3,15).add_years(i32::MAX), Timestamp::INFINITE);
+        assert_eq!( date(2024,3,15).add_years(i32::MIN), -Timestamp::INFINITE);
+
+        // valid dates which are out of the timestamp range
+        assert_eq!( date(2024,3,15).add_years(230), Timestamp::INFINITE);
+        assert_eq!( date(2024,3,15).add_years(300), Timestamp::INFINITE);
+        assert_eq!( date(2024,3,15).add_years(-400), -Timestamp::INFINITE);
+        assert_eq!( date(2300,1,1), Timestamp::INFINITE);
+        assert_eq!( date(1600,1,1), -Timestamp::INFINITE);
+    }
+
+    #[test]
+    fn time_of_day()
+    {
+        let t = Timestamp::from_origin(TimeValue::from_days(3) + TimeValue::from_hours(14) + TimeValue::from_ticks(5));
+        assert_eq!( t.time_of_day(), TimeValue::from_hours(14) + TimeValue::from_ticks(5));
+        assert_eq!( t.hour(), 14);
+        assert_eq!( Timestamp::EPOCH.time_of_day(), TimeValue::default());
+        assert_eq!( Timestamp::EPOCH.hour(), 0);
+
+        // before 1970
+        let t = Timestamp::from_origin(-TimeValue::from_hours(1));
+        assert_eq!( t.time_of_day(), TimeValue::from_hours(23));
+        assert_eq!( t.hour(), 23);
+        assert_eq!( Timestamp::INFINITE.time_of_day(), TimeValue::default());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn calendar()
+    {
+        use chrono::{NaiveDate, Weekday};
+        let day = NaiveDate::from_ymd_opt(2024,3,15).unwrap();
+        let t = Timestamp::from(day.and_hms_opt(10,30,0).unwrap());
+        assert_eq!( t.date(), day);
+        assert_eq!( t.weekday(), Weekday::Fri);
+        assert_eq!( t.hour(), 10);
+        assert_eq!( t.time_of_day(), TimeValue::from_mins(630));
+        assert_eq!( Timestamp::EPOCH.weekday(), Weekday::Thu);
+        assert_eq!( (Timestamp::EPOCH - TimeValue::from_ticks(1)).date(), NaiveDate::from_ymd_opt(1969,12,31).unwrap());
+    }
+}
@@ -1,6 +1,7 @@
-use std::iter::Sum;
-use std::ops::{Add, AddAssign, Neg, Sub, SubAssign};
-use std::time;
+use core::cmp::Ordering;
+use core::iter::Sum;
+use core::ops::{Add, AddAssign, Neg, Rem, RemAssign, Sub, SubAssign};
+use core::time;
 use crate::*;
 
 
@@ -55,6 +56,8 @@ impl TimeValue {
     {
         if sec > MAX_SEC {
             TimeValue::INFINITE
+        } else if sec < -MAX_SEC {
+            -TimeValue::INFINITE
         } else {
             Self(sec << SUBSEC_BITLEN)
         }
@@ -66,6 +69,8 @@ impl TimeValue {
         let frac = t - sec*unit;
         if sec > MAX_SEC {
             TimeValue::INFINITE
+        } else if sec < -MAX_SEC {
+            -TimeValue::INFINITE
         } else {
             // we should separate in order to deal with overflow
             Self((sec<<SUBSEC_BITLEN) + (frac << SUBSEC_BITLEN)/unit)
@@ -140,6 +145,113 @@ impl TimeValue {
     #[inline]
     pub fn as_secs(&self) -> i64 { self.0 >> SUBSEC_BITLEN }
 
+    /// Number of whole seconds as a `i32`, if it fits
+    ///
+    /// The fraction of a second is truncated toward zero (unlike [`Self::as_secs`]
+    /// which floors it), so `-1.5s` gives `-1`.
+    /// Returns `None` if the value is infinite or out of the `i32` range.
+    #[inline]
+    pub fn as_secs_i32(&self) -> Option<i32> { self.as_units_i32(1) }
+
+    /// Number of whole minutes as a `i32`, if it fits (see [`Self::as_secs_i32`])
+    #[inline]
+    pub fn as_mins_i32(&self) -> Option<i32> { self.as_units_i32(60) }
+
+    /// Number of whole hours as a `i32`, if it fits (see [`Self::as_secs_i32`])
+    #[inline]
+    pub fn as_hours_i32(&self) -> Option<i32> { self.as_units_i32(3600) }
+
+    fn as_units_i32(&self, secs_per_unit: i64) -> Option<i32>
+    {
+        if self.is_finite() {
+            i32::try_from(self.0 / ((1_i64 << SUBSEC_BITLEN) * secs_per_unit)).ok()
+        } else {
+            None
+        }
+    }
+
+    /// Duration as a (fractional) number of seconds
+    ///
+    /// Infinite time values are converted to infinite floats.
+    ///
+    /// __Important note__: a `f64` has a 53-bit mantissa and the fractional part
+    /// of a second is held on 30 bits so the tick precision is lost for time values
+    /// greater than 2^23 seconds (about 97 days).
+    #[inline]
+    pub fn as_secs_f64(&self) -> f64
+    {
+        if self.is_future_infinite() {
+            f64::INFINITY
+        } else if self.is_past_infinite() {
+            f64::NEG_INFINITY
+        } else {
+            self.0 as f64 / (1_i64 << SUBSEC_BITLEN) as f64
+        }
+    }
+
+    /// Duration from a (fractional) number of seconds
+    ///
+    /// The value is rounded to the nearest tick and saturated to infinite
+    /// if it is out of the range of finite time values.
+    ///
+    /// # Panics
+    /// Panics if the given number of seconds is NaN.
+    ///
+    /// __Important note__: see [`Self::as_secs_f64`] about precision loss
+    /// for large values.
+    #[inline]
+    pub fn from_secs_f64(secs: f64) -> Self
+    {
+        assert!( !secs.is_nan(), "can’t convert NaN to time value");
+        let ticks = secs * (1_i64 << SUBSEC_BITLEN) as f64;
+        if ticks >= INFINITE_TIME_VALUE as f64 {
+            TimeValue::INFINITE
+        } else if ticks <= -INFINITE_TIME_VALUE as f64 {
+            -TimeValue::INFINITE
+        } else {
+            // rounds to the nearest tick (f64::round needs std)
+            let t = ticks as i64;
+            let fract = ticks - t as f64;
+            Self(if fract >= 0.5 { t + 1 } else if fract <= -0.5 { t - 1 } else { t })
+        }
+    }
+
+    /// Period of a frequency (in hertz)
+    ///
+    /// This is the duration `1/hz`, rounded to the nearest tick (see [`Self::from_secs_f64`]).
+    /// A null frequency gives an infinite period and an infinite frequency gives
+    /// a null period.
+    ///
+    /// # Panics
+    /// Panics if the frequency is NaN.
+    #[inline]
+    pub fn from_hz(hz: f64) -> Self { Self::from_secs_f64(1. / hz) }
+
+    /// Converts to a standard duration, without panicking
+    ///
+    /// Unlike the conversion `Duration::from`, this returns an error if
+    /// the time value is out of the range of standard durations:
+    /// [`TimeError::PastOverflow`] if it is negative (or -&infin;) and
+    /// [`TimeError::FutureOverflow`] if it is +&infin;.
+    #[inline]
+    pub fn try_into_std(&self) -> TimeResult<time::Duration>
+    {
+        if self.is_future_infinite() {
+            Err(TimeError::FutureOverflow)
+        } else if self.is_strictly_negative() {
+            Err(TimeError::PastOverflow)
+        } else {
+            Ok((*self).into())
+        }
+    }
+
+    /// Frequency (in hertz) of a period
+    ///
+    /// This is the inverse of [`Self::as_secs_f64`]: a null duration gives
+    /// an infinite frequency and an infinite duration gives a null frequency.
+    #[inline]
+    pub fn as_hz(&self) -> f64 { 1. / self.as_secs_f64() }
+
     /// Fractional part of a time value (milliseconds)
     ///
     /// __Important note__: the fractional part of a second is represented in ticks which is
@@ -170,6 +282,7 @@ impl TimeValue {
         ((((self.0 & SUBSEC_BITMASK)  as u64 * 10_000_000_000 + 5_000_000_000) >> SUBSEC_BITLEN)/10) as i32
     }
 
+    #[cfg(feature = "std")]
     #[inline]
     pub fn to_duration(&self) -> chrono::Duration { (*self).into() }
 
@@ -188,6 +301,94 @@ impl TimeValue {
     #[inline]
     pub fn is_strictly_negative(&self) -> bool { self.0 < 0 }
 
+    /// Adds two time values without panicking
+    ///
+    /// The result is saturated to infinite as the `+` operator but, when
+    /// adding two opposite infinite values (which panics with the operator),
+    /// the result is zero by convention.
+    #[inline]
+    pub fn saturating_add(self, other: TimeValue) -> TimeValue
+    {
+        if (self.is_future_infinite() && other.is_past_infinite())
+            || (self.is_past_infinite() && other.is_future_infinite()) {
+            TimeValue::default()
+        } else {
+            self + other
+        }
+    }
+
+    /// Multiplies a time value by an integer without panicking
+    ///
+    /// The result is saturated to infinite if it overflows.
+    /// An infinite value multiplied by zero is zero by convention.
+    #[inline]
+    pub fn saturating_mul(self, n: i64) -> TimeValue
+    {
+        TimeValue::from_ticks(self.0.saturating_mul(n))
+    }
+
+    /// Restricts a time value to a range
+    ///
+    /// Unlike [`Ord::clamp`], it does not panic if `min > max`
+    /// (then `max` is returned). The infinite values are the natural extremes,
+    /// so `-TimeValue::INFINITE` or `TimeValue::INFINITE` means no clamping
+    /// on that side.
+    #[inline]
+    pub fn clamp(self, min: TimeValue, max: TimeValue) -> TimeValue
+    {
+        self.max(min).min(max)
+    }
+
+    /// Linear interpolation between two time values
+    ///
+    /// Returns `a + (b-a)*t`, rounded to the nearest tick, so `t=0` gives `a`
+    /// and `t=1` gives `b`. The factor is not restricted to `[0,1]` and a value
+    /// outside this range extrapolates (see [`Self::lerp_clamped`]).
+    ///
+    /// # Panics
+    /// Panics if `t` is NaN or if `a` and `b` are opposite infinite values.
+    #[inline]
+    pub fn lerp(a: TimeValue, b: TimeValue, t: f64) -> TimeValue
+    {
+        if a == b { a } else { a + (b - a) * t }
+    }
+
+    /// Linear interpolation between two time values, restricted to them
+    ///
+    /// Same as [`Self::lerp`] but the factor `t` is first clamped to `[0,1]`,
+    /// so the result always lies between `a` and `b`.
+    #[inline]
+    pub fn lerp_clamped(a: TimeValue, b: TimeValue, t: f64) -> TimeValue
+    {
+        assert!( !t.is_nan(), "can’t interpolate with NaN");
+        TimeValue::lerp(a, b, t.clamp(0., 1.))
+    }
+
+    /// Exact division by an integer
+    ///
+    /// Returns `None` if the number of ticks is not a multiple of `n`
+    /// (so there is no rounding), if `n` is zero or if the value is infinite.
+    #[inline]
+    pub fn checked_div(self, n: i64) -> Option<TimeValue>
+    {
+        if !self.is_finite() || n == 0 || self.0 % n != 0 {
+            None
+        } else {
+            Some(TimeValue(self.0 / n))
+        }
+    }
+
+    /// Checks if this time value is a whole number of periods
+    ///
+    /// In other words, it checks if `period` divides this value (with no tick
+    /// remainder). Returns `false` if one of them is infinite or if the period
+    /// is zero.
+    #[inline]
+    pub fn divides(self, period: TimeValue) -> bool
+    {
+        self.is_finite() && period.is_finite() && !period.is_zero() && self.0 % period.0 == 0
+    }
+
     #[inline]
     pub fn floor(self, period:TimeValue) -> Self
     {
@@ -207,10 +408,113 @@ impl TimeValue {
             if self.0 > 0 {
                 ((self.0-1)/period.0+1)*period.0
             } else {
-                ((self.0-1)/period.0)*period.0
+                (self.0/period.0)*period.0
             }
         )
     }
+
+    /// Rounds to the nearest multiple of a unit
+    ///
+    /// The half-way values are rounded away from zero so the rounding is
+    /// symmetric for negative values (i.e. `(-t).round_to(u) == -t.round_to(u)`).
+    /// Infinite values remain infinite.
+    #[inline]
+    pub fn round_to(self, unit: TimeValue) -> Self
+    {
+        if !self.is_finite() { return self; }
+        let lower = self.floor(unit);
+        let upper = self.ceil(unit);
+        match (self - lower).cmp(&(upper - self)) {
+            Ordering::Less => lower,
+            Ordering::Greater => upper,
+            Ordering::Equal => if self.is_positive() { upper } else { lower }
+        }
+    }
+
+    /// Truncates to a multiple of a unit (towards zero)
+    ///
+    /// Infinite values remain infinite.
+    #[inline]
+    pub fn truncate_to(self, unit: TimeValue) -> Self
+    {
+        if !self.is_finite() {
+            self
+        } else if self.is_positive() {
+            self.floor(unit)
+        } else {
+            self.ceil(unit)
+        }
+    }
+
+    /// Rounds to the nearest second (see [`Self::round_to`])
+    #[inline]
+    pub fn round_to_secs(self) -> Self { self.round_to(TimeValue::from_secs(1)) }
+
+    /// Truncates to whole milliseconds (towards zero)
+    ///
+    /// The result equals [`Self::from_millis`] of the number of whole milliseconds.
+    /// Notice that, since a millisecond is not an exact number of ticks,
+    /// this is not the same as `self.truncate_to(TimeValue::from_millis(1))`.
+    #[inline]
+    pub fn truncate_to_millis(self) -> Self { self.truncate_to_nanos(1_000_000) }
+
+    /// Truncates to whole microseconds (towards zero)
+    ///
+    /// The result equals [`Self::from_micros`] of the number of whole microseconds
+    /// (see also [`Self::truncate_to_millis`]).
+    #[inline]
+    pub fn truncate_to_micros(self) -> Self { self.truncate_to_nanos(1_000) }
+
+    /// Greatest common divisor (in ticks)
+    ///
+    /// The result is always positive (the signs are ignored) and,
+    /// by convention, `x.gcd(0) == x.abs()`.
+    /// An infinite value is considered as a multiple of any value
+    /// so `x.gcd(oo) == x.abs()`.
+    #[inline]
+    pub fn gcd(self, other: TimeValue) -> TimeValue
+    {
+        match (self.is_finite(), other.is_finite()) {
+            (false, false) => TimeValue::INFINITE,
+            (false, true) => TimeValue(other.0.abs()),
+            (true, false) => TimeValue(self.0.abs()),
+            (true, true) => {
+                let (mut a, mut b) = (self.0.unsigned_abs(), other.0.unsigned_abs());
+                while b != 0 { (a, b) = (b, a % b); }
+                TimeValue(a as i64)
+            }
+        }
+    }
+
+    fn truncate_to_nanos(self, unit: i128) -> Self
+    {
+        if !self.is_finite() { return self; }
+        // truncating towards zero, so negative values are symmetric
+        let ticks = self.0 as i128;
+        let nanos = ticks.signum() * ((ticks.abs() * 1_000_000_000) >> SUBSEC_BITLEN);
+        let nanos = nanos - nanos % unit;
+        Self(((nanos << SUBSEC_BITLEN) / 1_000_000_000) as i64)
+    }
+}
+
+
+/// Least common multiple (in ticks) of two time values
+///
+/// The result is always positive (the signs are ignored) and is
+/// saturated to infinite if it overflows (or if one value is infinite).
+/// If one of the values is zero, the result is zero.
+#[inline]
+pub fn lcm(a: TimeValue, b: TimeValue) -> TimeValue
+{
+    if a.is_zero() || b.is_zero() {
+        TimeValue::default()
+    } else if !a.is_finite() || !b.is_finite() {
+        TimeValue::INFINITE
+    } else {
+        (a.0 / a.gcd(b).0).checked_mul(b.0)
+            .map(|t| TimeValue::from_ticks(t.saturating_abs()))
+            .unwrap_or(TimeValue::INFINITE)
+    }
 }
 
 
@@ -246,6 +550,7 @@ impl TimePoint for TimeValue
     }
 }
 
+#[cfg(feature = "std")]
 impl From<TimeValue> for chrono::Duration
 {
     #[inline]
@@ -256,6 +561,7 @@ impl From<TimeValue> for chrono::Duration
     }
 }
 
+#[cfg(feature = "std")]
 impl From<chrono::Duration> for TimeValue
 {
     #[inline]
@@ -270,6 +576,11 @@ impl From<chrono::Duration> for TimeValue
 
 impl From<TimeValue> for time::Duration
 {
+    /// Converts a positive time value to a duration
+    ///
+    /// # Panics
+    /// Panics if the time value is negative: prefer [`TimeValue::try_into_std`]
+    /// when the time value is not known to be positive.
     #[inline]
     fn from(value: TimeValue) -> Self {
         assert!( value.0 >= 0 , "can’t convert negative time value to duration");
@@ -352,14 +663,165 @@ impl SubAssign for TimeValue {
     #[inline] fn sub_assign(&mut self, v: TimeValue) { *self += -v }
 }
 
+impl Rem for TimeValue {
+    type Output = Self;
+
+    /// Remainder of the division by a period
+    ///
+    /// As for integers, the remainder has the sign of this value
+    /// (use [`TimeValue::floor`] for a remainder which is always positive).
+    /// A finite value is its own remainder by an infinite period.
+    ///
+    /// # Panics
+    /// Panics if the period is zero or if this value is infinite.
+    #[inline]
+    fn rem(self, period: Self) -> Self
+    {
+        assert!( !period.is_zero(), "time error: remainder by a null period");
+        assert!( self.is_finite(), "time error: remainder of an infinite value");
+        if period.is_finite() { Self(self.0 % period.0) } else { self }
+    }
+}
+
+impl RemAssign for TimeValue {
+    #[inline] fn rem_assign(&mut self, period: TimeValue) { *self = *self % period }
+}
+
 impl Sum for TimeValue {
     fn sum<I: Iterator<Item=Self>>(iter: I) -> Self {
         iter.fold(TimeValue::default(), |a,b| a+b)
     }
 }
 
+
+/// # An exact accumulator of time values
+///
+/// Summing time values with `+` (or with [`Iterator::sum`]) saturates as soon
+/// as a partial sum overflows, even if the final result is finite. This accumulator
+/// keeps the partial sums exact (on 128 bits) and only saturates
+/// the final result (see [`Self::finish`]).
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct TimeValueAccumulator {
+    ticks: i128,
+    future_infinite: bool,
+    past_infinite: bool
+}
+
+impl TimeValueAccumulator {
+
+    /// Creates a new accumulator (set to zero)
+    #[inline]
+    pub fn new() -> Self { Self::default() }
+
+    /// Adds a time value to the accumulator
+    #[inline]
+    pub fn add(&mut self, t: TimeValue)
+    {
+        if t.is_future_infinite() {
+            self.future_infinite = true;
+        } else if t.is_past_infinite() {
+            self.past_infinite = true;
+        } else {
+            self.ticks += t.0 as i128;
+        }
+    }
+
+    /// Subtracts a time value from the accumulator
+    #[inline]
+    pub fn sub(&mut self, t: TimeValue) { self.add(-t) }
+
+    /// Gets the accumulated time value
+    ///
+    /// The result is saturated to infinite if it is out of the range of finite time values.
+    ///
+    /// # Panics
+    /// Panics if both +&infin; and -&infin; were accumulated.
+    pub fn finish(&self) -> TimeValue
+    {
+        if self.future_infinite {
+            assert!(!self.past_infinite, "time error: +oo + -oo");
+            TimeValue::INFINITE
+        } else if self.past_infinite {
+            -TimeValue::INFINITE
+        } else if self.ticks >= INFINITE_TIME_VALUE as i128 {
+            TimeValue::INFINITE
+        } else if self.ticks <= -INFINITE_TIME_VALUE as i128 {
+            -TimeValue::INFINITE
+        } else {
+            TimeValue(self.ticks as i64)
+        }
+    }
+}
+
 #[cfg(test)] mod tests {
-    use crate::TimeValue;
+    use crate::{INFINITE_TIME_VALUE, lcm, TimePoint, TimeValue, TimeValueAccumulator};
+
+    #[test]
+    fn checked_div() {
+        let t = TimeValue::from_ticks;
+        assert_eq!( t(12).checked_div(4), Some(t(3)));
+        assert_eq!( t(-12).checked_div(4), Some(t(-3)));
+        assert_eq!( t(12).checked_div(-3), Some(t(-4)));
+        assert_eq!( t(12).checked_div(5), None);
+        assert_eq!( t(12).checked_div(0), None);
+        assert_eq!( t(0).checked_div(7), Some(t(0)));
+        assert_eq!( TimeValue::INFINITE.checked_div(1), None);
+        assert_eq!( TimeValue::from_hours(1).checked_div(60), Some(TimeValue::from_mins(1)));
+
+        assert!( TimeValue::from_hours(3).divides(TimeValue::from_mins(45)));
+        assert!( !TimeValue::from_hours(3).divides(TimeValue::from_mins(50)));
+        assert!( (-TimeValue::from_hours(3)).divides(TimeValue::from_mins(45)));
+        assert!( TimeValue::default().divides(TimeValue::from_mins(45)));
+        assert!( !TimeValue::from_hours(3).divides(TimeValue::default()));
+        assert!( !TimeValue::INFINITE.divides(TimeValue::from_mins(45)));
+        assert!( !TimeValue::from_hours(3).divides(TimeValue::INFINITE));
+    }
+
+    #[test]
+    fn rem() {
+        let t = TimeValue::from_ticks;
+        assert_eq!( t(17) % t(5), t(2));
+        assert_eq!( t(-17) % t(5), t(-2));
+        assert_eq!( t(17) % t(-5), t(2));
+        assert_eq!( t(15) % t(5), t(0));
+        assert_eq!( t(17) % TimeValue::INFINITE, t(17));
+        assert_eq!( TimeValue::from_mins(100) % TimeValue::from_hours(1), TimeValue::from_mins(40));
+
+        let mut d = TimeValue::from_secs(3725);
+        d %= TimeValue::from_hours(1);
+        assert_eq!( d, TimeValue::from_secs(125));
+    }
+
+    #[test]
+    #[should_panic]
+    fn rem_null_period() {
+        let _ = TimeValue::from_secs(3) % TimeValue::default();
+    }
+
+    #[test]
+    #[should_panic]
+    fn rem_infinite() {
+        let _ = TimeValue::INFINITE % TimeValue::from_secs(3);
+    }
+
+    #[test]
+    fn gcd_lcm() {
+        let t = TimeValue::from_ticks;
+        assert_eq!( t(12).gcd(t(18)), t(6));
+        assert_eq!( t(-12).gcd(t(18)), t(6));
+        assert_eq!( t(12).gcd(t(0)), t(12));
+        assert_eq!( t(0).gcd(t(-12)), t(12));
+        assert_eq!( t(0).gcd(t(0)), t(0));
+        assert_eq!( t(12).gcd(TimeValue::INFINITE), t(12));
+        assert_eq!( TimeValue::from_hours(1).gcd(TimeValue::from_mins(45)), TimeValue::from_mins(15));
+
+        assert_eq!( lcm(t(4), t(6)), t(12));
+        assert_eq!( lcm(t(-4), t(6)), t(12));
+        assert_eq!( lcm(t(4), t(0)), t(0));
+        assert_eq!( lcm(TimeValue::from_hours(1), TimeValue::from_mins(45)), TimeValue::from_hours(3));
+        assert_eq!( lcm(t(1<<40), t((1<<40)+1)), TimeValue::INFINITE);
+        assert_eq!( lcm(t(5), -TimeValue::INFINITE), TimeValue::INFINITE);
+    }
 
     #[test]
     fn ceil() {
@@ -368,9 +830,36 @@ impl Sum for TimeValue {
         assert_eq!( TimeValue::from_ticks(0).ceil(TimeValue::from_ticks(13)).as_ticks(), 0);
         assert_eq!( TimeValue::from_ticks(-13).ceil(TimeValue::from_ticks(5)).as_ticks(), -10);
         assert_eq!( TimeValue::from_ticks(-15).ceil(TimeValue::from_ticks(5)).as_ticks(), -15);
+
+        // negative values near zero or just above a multiple of the period
+        let ceil = |t, p| TimeValue::from_ticks(t).ceil(TimeValue::from_ticks(p)).as_ticks();
+        assert_eq!( ceil(-1, 5), 0);
+        assert_eq!( ceil(-4, 5), 0);
+        assert_eq!( ceil(-5, 5), -5);
+        assert_eq!( ceil(-9, 5), -5);
+        assert_eq!( ceil(-10, 5), -10);
+        assert_eq!( ceil(-1, 1), -1);
+        assert_eq!( ceil(1, 5), 5);
+        assert_eq!( ceil(5, 5), 5);
+
+        // the ceil is the smallest multiple of the period not below the value
+        for t in -30..=30 {
+            let c = ceil(t, 7);
+            assert!( c >= t && c - t < 7 && c % 7 == 0, "ceil of {t}");
+            assert_eq!( c, -TimeValue::from_ticks(-t).floor(TimeValue::from_ticks(7)).as_ticks());
+        }
     }
 
 
+    #[test]
+    fn saturation() {
+        assert_eq!( TimeValue::from_secs(i64::MAX), TimeValue::INFINITE);
+        assert_eq!( TimeValue::from_secs(i64::MIN), -TimeValue::INFINITE);
+        assert_eq!( TimeValue::from_millis(i64::MIN), -TimeValue::INFINITE);
+        assert_eq!( TimeValue::from_days(-i64::MAX/2), -TimeValue::INFINITE);
+        assert!( TimeValue::from_secs(-(1 << 32)).is_strictly_negative());
+    }
+
     #[test]
     fn floor() {
         assert_eq!( TimeValue::from_ticks(13).floor(TimeValue::from_ticks(5)).as_ticks(), 10);
@@ -380,4 +869,146 @@ impl Sum for TimeValue {
         assert_eq!( TimeValue::from_ticks(-13).floor(TimeValue::from_ticks(5)).as_ticks(), -15);
         assert_eq!( TimeValue::from_ticks(-13).floor(TimeValue::from_ticks(13)).as_ticks(), -13);
     }
+
+    #[test]
+    fn accumulator() {
+        let big = TimeValue::from_ticks(i64::MAX / 2);
+        let mut acc = TimeValueAccumulator::new();
+        (0..1000).for_each(|_| acc.add(big));
+        (0..999).for_each(|_| acc.sub(big));
+        assert_eq!( acc.finish(), big);
+
+        // the partial sums saturate when using the operators (or sum)
+        let sum = (0..1000).map(|_| big).chain((0..999).map(|_| -big)).sum::<TimeValue>();
+        assert_eq!( sum, TimeValue::INFINITE);
+        assert_eq!( (0..3).map(|_| big).sum::<TimeValue>(), TimeValue::INFINITE);
+
+        acc.add(-TimeValue::INFINITE);
+        assert_eq!( acc.finish(), -TimeValue::INFINITE);
+    }
+
+    #[test]
+    fn round_to() {
+        let t = TimeValue::from_ticks;
+        assert_eq!( t(14).round_to(t(10)), t(10) );
+        assert_eq!( t(15).round_to(t(10)), t(20) );
+        assert_eq!( t(16).round_to(t(10)), t(20) );
+        assert_eq!( t(-14).round_to(t(10)), t(-10) );
+        assert_eq!( t(-15).round_to(t(10)), t(-20) );
+        assert_eq!( t(-16).round_to(t(10)), t(-20) );
+        assert_eq!( t(20).round_to(t(10)), t(20) );
+        assert_eq!( t(0).round_to(t(10)), t(0) );
+
+        assert_eq!( t(19).truncate_to(t(10)), t(10) );
+        assert_eq!( t(-19).truncate_to(t(10)), t(-10) );
+
+        assert_eq!( TimeValue::INFINITE.round_to(t(10)), TimeValue::INFINITE );
+        assert_eq!( (-TimeValue::INFINITE).round_to(t(10)), -TimeValue::INFINITE );
+        assert_eq!( TimeValue::INFINITE.truncate_to_millis(), TimeValue::INFINITE );
+
+        assert_eq!( TimeValue::from_millis(1499).round_to_secs(), TimeValue::from_secs(1) );
+        assert_eq!( TimeValue::from_millis(1500).round_to_secs(), TimeValue::from_secs(2) );
+        assert_eq!( TimeValue::from_millis(-1500).round_to_secs(), TimeValue::from_secs(-2) );
+        assert_eq!( (TimeValue::from_millis(12) + TimeValue::from_micros(7)).truncate_to_millis(), TimeValue::from_millis(12) );
+        assert_eq!( -(TimeValue::from_micros(12) + TimeValue::from_nanos(7)).truncate_to_micros(), -TimeValue::from_micros(12) );
+    }
+
+    #[test]
+    fn saturating() {
+        let t = TimeValue::from_ticks;
+        assert_eq!( t(3).saturating_add(t(4)), t(7) );
+        assert_eq!( t(3).saturating_add(TimeValue::INFINITE), TimeValue::INFINITE );
+        assert_eq!( t(INFINITE_TIME_VALUE-1).saturating_add(t(4)), TimeValue::INFINITE );
+        assert_eq!( (-TimeValue::INFINITE).saturating_add(-TimeValue::INFINITE), -TimeValue::INFINITE );
+        assert_eq!( TimeValue::INFINITE.saturating_add(-TimeValue::INFINITE), TimeValue::default() );
+        assert_eq!( (-TimeValue::INFINITE).saturating_add(TimeValue::INFINITE), TimeValue::default() );
+
+        assert_eq!( t(3).saturating_mul(-4), t(-12) );
+        assert_eq!( t(INFINITE_TIME_VALUE/2).saturating_mul(3), TimeValue::INFINITE );
+        assert_eq!( t(INFINITE_TIME_VALUE/2).saturating_mul(-3), -TimeValue::INFINITE );
+        assert_eq!( TimeValue::INFINITE.saturating_mul(-1), -TimeValue::INFINITE );
+        assert_eq!( (-TimeValue::INFINITE).saturating_mul(i64::MIN), TimeValue::INFINITE );
+        assert_eq!( TimeValue::INFINITE.saturating_mul(0), TimeValue::default() );
+    }
+
+    #[test]
+    fn as_i32() {
+        assert_eq!( TimeValue::from_secs(90).as_secs_i32(), Some(90) );
+        assert_eq!( TimeValue::from_secs(90).as_mins_i32(), Some(1) );
+        assert_eq!( TimeValue::from_mins(-90).as_hours_i32(), Some(-1) );
+        assert_eq!( TimeValue::from_millis(-1500).as_secs_i32(), Some(-1) );
+        assert_eq!( TimeValue::from_millis(-1500).as_secs(), -2 );
+        assert_eq!( TimeValue::from_millis(999).as_secs_i32(), Some(0) );
+        assert_eq!( TimeValue::from_secs(i32::MAX as i64).as_secs_i32(), Some(i32::MAX) );
+        assert_eq!( TimeValue::from_secs(i32::MAX as i64 + 1).as_secs_i32(), None );
+        assert_eq!( TimeValue::from_secs(i32::MAX as i64 + 1).as_mins_i32(), Some(35_791_394) );
+        assert_eq!( TimeValue::from_secs(i32::MIN as i64).as_secs_i32(), Some(i32::MIN) );
+        assert_eq!( TimeValue::INFINITE.as_hours_i32(), None );
+        assert_eq!( (-TimeValue::INFINITE).as_secs_i32(), None );
+    }
+
+    #[test]
+    fn clamp() {
+        let t = TimeValue::from_ticks;
+        assert_eq!( t(5).clamp(t(0), t(10)), t(5) );
+        assert_eq!( t(-5).clamp(t(0), t(10)), t(0) );
+        assert_eq!( t(15).clamp(t(0), t(10)), t(10) );
+        assert_eq!( t(15).clamp(t(0), TimeValue::INFINITE), t(15) );
+        assert_eq!( t(-15).clamp(-TimeValue::INFINITE, t(10)), t(-15) );
+        assert_eq!( TimeValue::INFINITE.clamp(t(0), t(10)), t(10) );
+        assert_eq!( t(5).clamp(t(10), t(0)), t(0) );
+    }
+
+    #[test]
+    fn lerp() {
+        let t = TimeValue::from_secs;
+        assert_eq!( TimeValue::lerp(t(10), t(20), 0.), t(10) );
+        assert_eq!( TimeValue::lerp(t(10), t(20), 1.), t(20) );
+        assert_eq!( TimeValue::lerp(t(10), t(20), 0.25), TimeValue::from_millis(12500) );
+        assert_eq!( TimeValue::lerp(t(20), t(10), 0.5), t(15) );
+        assert_eq!( TimeValue::lerp(t(10), t(20), 1.5), t(25) );
+        assert_eq!( TimeValue::lerp(t(10), t(20), -1.), t(0) );
+        assert_eq!( TimeValue::lerp(t(10), TimeValue::INFINITE, 0.5), TimeValue::INFINITE );
+        assert_eq!( TimeValue::lerp(TimeValue::INFINITE, TimeValue::INFINITE, 0.5), TimeValue::INFINITE );
+
+        assert_eq!( TimeValue::lerp_clamped(t(10), t(20), 1.5), t(20) );
+        assert_eq!( TimeValue::lerp_clamped(t(10), t(20), -1.), t(10) );
+        assert_eq!( TimeValue::lerp_clamped(t(10), t(20), 0.5), t(15) );
+    }
+
+    #[test]
+    fn secs_f64() {
+        assert_eq!( TimeValue::from_millis(1500).as_secs_f64(), 1.5);
+        assert_eq!( TimeValue::from_secs(-3).as_secs_f64(), -3.);
+        assert_eq!( TimeValue::from_secs_f64(1.5), TimeValue::from_millis(1500));
+        assert_eq!( TimeValue::from_secs_f64(-0.25), -TimeValue::from_millis(250));
+        assert_eq!( TimeValue::from_secs_f64(1e30), TimeValue::INFINITE);
+        assert_eq!( TimeValue::from_secs_f64(f64::NEG_INFINITY), -TimeValue::INFINITE);
+        assert_eq!( TimeValue::INFINITE.as_secs_f64(), f64::INFINITY);
+    }
+
+    #[test]
+    fn try_into_std() {
+        use core::time::Duration;
+        use crate::TimeError;
+        assert_eq!( TimeValue::from_millis(1500).try_into_std(), Ok(Duration::from_millis(1500)));
+        assert_eq!( TimeValue::default().try_into_std(), Ok(Duration::ZERO));
+        assert_eq!( TimeValue::from_secs(-1).try_into_std(), Err(TimeError::PastOverflow));
+        assert_eq!( TimeValue::from_ticks(-1).try_into_std(), Err(TimeError::PastOverflow));
+        assert_eq!( (-TimeValue::INFINITE).try_into_std(), Err(TimeError::PastOverflow));
+        assert_eq!( TimeValue::INFINITE.try_into_std(), Err(TimeError::FutureOverflow));
+    }
+
+    #[test]
+    fn hz() {
+        assert_eq!( TimeValue::from_hz(4.), TimeValue::from_millis(250));
+        assert_eq!( TimeValue::from_hz(0.5), TimeValue::from_secs(2));
+        assert_eq!( TimeValue::from_hz(0.), TimeValue::INFINITE);
+        assert_eq!( TimeValue::from_hz(f64::INFINITY), TimeValue::default());
+        assert_eq!( TimeValue::from_millis(250).as_hz(), 4.);
+        assert_eq!( TimeValue::from_secs(2).as_hz(), 0.5);
+        assert_eq!( TimeValue::default().as_hz(), f64::INFINITY);
+        assert_eq!( TimeValue::INFINITE.as_hz(), 0.);
+        assert_eq!( TimeValue::from_hz(TimeValue::from_millis(125).as_hz()), TimeValue::from_millis(125));
+    }
 }
\ No newline at end of file